@@ -25,9 +25,10 @@ use ratatui::{
 };
 use tui_scrollview::{ScrollView, ScrollViewState};
 use rayon::prelude::*;
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::error;
-use std::io;
+use std::fs::File;
 use std::time::Instant;
 
 use strum::IntoEnumIterator;
@@ -36,6 +37,23 @@ use strum_macros::{Display, EnumIter, FromRepr};
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, Box<dyn error::Error>>;
 
+/// Prints the problems found while loading a crash dump to stderr.
+pub fn report_load_problems(crash_dump: &CrashDump) {
+    for warning in &crash_dump.warnings {
+        eprintln!("warning: {}", warning);
+    }
+    for error in &crash_dump.errors {
+        eprintln!("error: {}", error);
+    }
+    let truncation = &crash_dump.truncation;
+    if truncation.truncated {
+        eprintln!(
+            "warning: the dump is truncated, {} section(s) incomplete",
+            truncation.incomplete_sections
+        );
+    }
+}
+
 /// Application.
 pub struct App<'a> {
     /// header
@@ -58,6 +76,8 @@ pub struct App<'a> {
 
     pub inspecting_pid: String,
     pub inspect_scroll_state: ScrollViewState,
+    pub hex_view: HexView,
+    pub term_expansion: TermExpansion,
 
     pub table_states: HashMap<SelectedTab, TableState>,
 
@@ -65,6 +85,34 @@ pub struct App<'a> {
 
     pub process_view_table: Table<'a>,
     pub process_view_state: ProcessViewState,
+    // offset of the section the process view failed to decode, to open it raw
+    pub failed_section: Option<u64>,
+
+    pub ets_table: Table<'a>,
+    pub ets_sort: EtsSortKey,
+
+    pub ports_table: Table<'a>,
+
+    pub schedulers_table: Table<'a>,
+
+    pub allocators_table: Table<'a>,
+
+    pub timers_table: Table<'a>,
+
+    pub atoms_view: AtomsView,
+
+    pub persistent_terms_table: Table<'a>,
+
+    pub distribution_table: Table<'a>,
+
+    pub funs_table: Table<'a>,
+
+    pub raw_sections_view: RawSectionsView,
+
+    pub modules_table: Table<'a>,
+    pub modules_sort: ModuleSortKey,
+    // module name -> pids running code in it, computed the first time the Modules tab is shown
+    pub module_users: Option<HashMap<String, Vec<String>>>,
 
     pub footer_text: HashMap<SelectedTab, String>,
 }
@@ -82,6 +130,92 @@ pub enum ProcessViewState {
     #[default]
     Stack,
     MessageQueue,
+    Dictionary,
+}
+
+/// Column the ETS table is sorted by, largest first.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum EtsSortKey {
+    #[default]
+    Words,
+    Objects,
+}
+
+/// State of the Atoms tab. The atoms are streamed from the dump the first time the tab is
+/// shown and again on every search; only the matching atoms are kept, in `tab_lists`.
+#[derive(Default)]
+pub struct AtomsView {
+    pub loaded: bool,
+    // Number of atoms in the `=atoms` section
+    pub total: usize,
+    pub families: Vec<AtomFamily>,
+    // The regex the atom list is filtered with, empty to show every atom
+    pub search: String,
+    // True while the search is being typed
+    pub editing: bool,
+    pub error: Option<String>,
+}
+
+/// State of the Raw Sections tab, which lists the sections of the dump by offset. The rows are
+/// built only for the visible window, as a dump can have millions of sections.
+#[derive(Default)]
+pub struct RawSectionsView {
+    // The listed sections, sorted by byte offset
+    pub sections: Vec<(Tag, IndexRow)>,
+    // True to list only the sections with a tag this version doesn't know
+    pub unknown_only: bool,
+    // Start offset and contents of the section shown in the preview
+    pub preview: Option<(String, String)>,
+}
+
+// Bytes of a section shown in the Raw Sections preview
+const RAW_SECTION_PREVIEW_LEN: usize = 64 * 1024;
+
+/// State of the hex viewer of the Inspector tab. The binaries of the inspected stack, heap,
+/// message queue or dictionary are chosen in turn, and the chosen one is read in full when
+/// the viewer is opened.
+#[derive(Default)]
+pub struct HexView {
+    // Binaries of the inspected view, in the order they are shown
+    pub binaries: Vec<BinaryTerm>,
+    pub selected: usize,
+    // Contents of the chosen binary while the viewer is open, or why they can't be read
+    pub contents: Option<Result<Vec<u8>, String>>,
+    // First line shown
+    pub scroll: usize,
+}
+
+// Bytes on a line of the hex viewer
+const HEX_VIEW_LINE_LEN: usize = 16;
+// Characters of the chosen binary or truncated term shown in the Inspector's title
+const INSPECT_TITLE_PREVIEW_LEN: usize = 40;
+
+/// Terms of the Inspector tab that the heap walker left truncated. The truncated terms of the
+/// inspected view are chosen in turn, and expanding the chosen one decodes it with a budget of
+/// its own wherever it is shown, until another process is inspected.
+#[derive(Default)]
+pub struct TermExpansion {
+    // Heap encoding of the truncated terms of the inspected view, in the order they are shown
+    pub truncated: Vec<String>,
+    pub selected: usize,
+    // Heap encoding of the terms expanded so far
+    pub expanded: HashSet<String>,
+}
+
+// The binaries and truncated terms of an inspected view, in the order they are shown
+#[derive(Default)]
+pub struct ViewTerms {
+    pub binaries: Vec<BinaryTerm>,
+    pub truncated: Vec<String>,
+}
+
+/// Column the Modules table is sorted by. Sizes sort largest first.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleSortKey {
+    Name,
+    #[default]
+    CurrentSize,
+    OldSize,
 }
 
 #[derive(Default, Clone, Copy, Display, FromRepr, EnumIter, PartialEq, Eq, Hash)]
@@ -95,6 +229,28 @@ pub enum SelectedTab {
     ProcessGroup,
     #[strum(to_string = "Process Info")]
     Process,
+    #[strum(to_string = "ETS Tables")]
+    Ets,
+    #[strum(to_string = "Ports")]
+    Ports,
+    #[strum(to_string = "Schedulers")]
+    Schedulers,
+    #[strum(to_string = "Allocators")]
+    Allocators,
+    #[strum(to_string = "Modules")]
+    Modules,
+    #[strum(to_string = "Funs")]
+    Funs,
+    #[strum(to_string = "Timers")]
+    Timers,
+    #[strum(to_string = "Atoms")]
+    Atoms,
+    #[strum(to_string = "Persistent Terms")]
+    PersistentTerms,
+    #[strum(to_string = "Distribution")]
+    Distribution,
+    #[strum(to_string = "Raw Sections")]
+    RawSections,
     #[strum(to_string = "Inspector")]
     Inspect,
 }
@@ -117,10 +273,27 @@ impl Default for App<'_> {
             ),
             process_group_table: Table::default(),
             process_view_state: ProcessViewState::default(),
+            failed_section: None,
             process_view_table: Table::default(),
+            ets_table: Table::default(),
+            ets_sort: EtsSortKey::default(),
+            ports_table: Table::default(),
+            schedulers_table: Table::default(),
+            allocators_table: Table::default(),
+            timers_table: Table::default(),
+            atoms_view: AtomsView::default(),
+            persistent_terms_table: Table::default(),
+            distribution_table: Table::default(),
+            funs_table: Table::default(),
+            raw_sections_view: RawSectionsView::default(),
+            modules_table: Table::default(),
+            modules_sort: ModuleSortKey::default(),
+            module_users: None,
             footer_text: HashMap::new(),
             inspecting_pid: "".to_string(),
             inspect_scroll_state: ScrollViewState::default(),
+            hex_view: HexView::default(),
+            term_expansion: TermExpansion::default(),
         }
     }
 }
@@ -132,6 +305,14 @@ impl App<'_> {
 
         let parser = parser::CDParser::new(&filepath).unwrap();
 
+        let index_now = Instant::now();
+        let index_map = parser.build_index().unwrap();
+        eprintln!("Building index took: {:.2?}", index_now.elapsed());
+        let parse_now = Instant::now();
+        let crash_dump = parser.parse(&index_map).unwrap();
+        eprintln!("Parsing everything took: {:.2?}", parse_now.elapsed());
+        report_load_problems(&crash_dump);
+
         let mut ret = Self::default();
         ret.filepath = filepath.clone();
 
@@ -144,11 +325,11 @@ impl App<'_> {
         // });
 
         
-        ret.index_map = parser.build_index().unwrap();
-        ret.crash_dump = parser.parse(&ret.index_map).unwrap();   
+        ret.index_map = index_map;
+        ret.crash_dump = crash_dump;
 
         //println!("heap addrs: {:?}", ret.crash_dump.all_heap_addresses);
-        //println!("binaries: {:?}", ret.crash_dump.visited_binaries);
+        //println!("binaries: {:?}", ret.crash_dump.binaries);
 
         ret.ancestor_map = parser::CDParser::create_descendants_table(&ret.crash_dump.processes);
         // for every ancestor:<children> mapping, we need to calculate the GroupInfo for each one if the pid exists
@@ -186,7 +367,7 @@ impl App<'_> {
         //     .iter()
         //     .map(|pid| match ret.crash_dump.processes.get(pid).unwrap() {
         //         InfoOrIndex::Info(proc_info) => {
-        //             let item = proc_info.ref_array();
+        //             let item = proc_info.ref_array(ret.crash_dump.preamble.word_size);
         //             Row::new(item)
         //         }
         //         _ => {
@@ -220,7 +401,7 @@ impl App<'_> {
                         match *process_ref.value() {
                             // Dereference the Ref to access the inner value
                             InfoOrIndex::Info(ref proc_info) => {
-                                let item = proc_info.ref_array();
+                                let item = proc_info.ref_array(ret.crash_dump.preamble.word_size);
                                 Row::new(item)
                             }
                             _ => {
@@ -320,8 +501,19 @@ impl App<'_> {
         .highlight_spacing(HighlightSpacing::Always)
         .block(Block::bordered().title(SelectedTab::Process.to_string()));
 
-        ret.footer_text.insert(SelectedTab::Process, "Press S for Stack, H for Heap, M for Message Queue | I to inspect contents |  < > to change tabs | Press q to quit".to_string());
-        ret.footer_text.insert(SelectedTab::Inspect, "Press I to return to process info  |  < > to change tabs | q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Process, "Press S for Stack, H for Heap, M for Message Queue, D for Dictionary | I to inspect contents |  < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Ets, "Press W to sort by Words, O to sort by Objects | Enter to jump to the owner process | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Ports, "Enter to jump to the connected process | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Schedulers, "Enter to jump to the current process | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Allocators, "Allocators in red have low carrier utilization (fragmentation suspects) | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Modules, "Press N to sort by Name, C by Current Size, O by Old Size | Enter to jump to the first user | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Funs, "Funs with the most references first | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Timers, "Processes with the most timers first | Enter to jump to the process | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Atoms, "Press / to search with a regex, Enter to apply, Esc to cancel | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::PersistentTerms, "Largest terms first | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Distribution, "Connected nodes first | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::RawSections, "Press U to toggle unknown sections only, E to jump to the next section that failed to load | < > to change tabs | Press q to quit".to_string());
+        ret.footer_text.insert(SelectedTab::Inspect, "Press I to return to process info | N/P to choose a binary, X to view it in hex | [ ] to choose a truncated term, E to expand it | < > to change tabs | q to quit".to_string());
 
         // if let Some(state) = ret.table_states.get_mut(&SelectedTab::Index) {
         //     if !ret.tab_lists[&SelectedTab::Index].is_empty() {
@@ -341,6 +533,377 @@ impl App<'_> {
             }
         }
 
+        ret.sort_ets(EtsSortKey::default());
+
+        ///////// Ports
+
+        let mut port_ids: Vec<String> = ret
+            .crash_dump
+            .ports
+            .iter()
+            .map(|port_ref| port_ref.key().clone())
+            .collect();
+        port_ids.sort_by_cached_key(|port_id| id_sort_key(port_id));
+        let port_rows: Vec<Row> = port_ids
+            .iter()
+            .map(|port_id| match ret.crash_dump.ports.get(port_id) {
+                Some(port_ref) => match *port_ref.value() {
+                    InfoOrIndex::Info(ref port_info) => Row::new(port_info.ref_array()),
+                    InfoOrIndex::Index(_) => {
+                        Row::new(vec![format!("Unexpected Index for port: {:?}", port_id)])
+                    }
+                },
+                None => Row::new(vec![format!("Port not found: {:?}", port_id)]),
+            })
+            .collect();
+        ret.tab_lists.insert(SelectedTab::Ports, port_ids);
+
+        let ports_header = PortInfo::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.ports_table = Table::new(
+            port_rows,
+            [
+                Constraint::Length(15),
+                Constraint::Length(15),
+                Constraint::Length(20),
+                Constraint::Length(50),
+                Constraint::Length(30),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(12),
+            ],
+        )
+        .header(ports_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Ports.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Ports) {
+            if !ret.tab_lists[&SelectedTab::Ports].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ///////// Schedulers
+
+        // Normal schedulers first, then the dirty CPU and dirty IO ones, each in id order
+        ret.crash_dump.schedulers.sort_by_key(|scheduler| match scheduler {
+            InfoOrIndex::Info(info) => (info.type_, info.id),
+            InfoOrIndex::Index(_) => (SchedulerType::default(), i32::MAX),
+        });
+        let schedulers: Vec<&SchedulerInfo> = ret
+            .crash_dump
+            .schedulers
+            .iter()
+            .filter_map(|scheduler| match scheduler {
+                InfoOrIndex::Info(info) => Some(info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        let scheduler_rows: Vec<Row> = schedulers
+            .iter()
+            .map(|scheduler| Row::new(scheduler.ref_array()))
+            .collect();
+        let scheduler_ids: Vec<String> = schedulers
+            .iter()
+            .map(|scheduler| format!("{}", scheduler.id))
+            .collect();
+        ret.tab_lists.insert(SelectedTab::Schedulers, scheduler_ids);
+
+        let schedulers_header = SchedulerInfo::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.schedulers_table = Table::new(
+            scheduler_rows,
+            [
+                Constraint::Length(5),
+                Constraint::Length(10),
+                Constraint::Length(40),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Length(16),
+                Constraint::Length(16),
+            ],
+        )
+        .header(schedulers_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Schedulers.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Schedulers) {
+            if !ret.tab_lists[&SelectedTab::Schedulers].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ///////// Allocators
+
+        let allocator_summaries = ret.crash_dump.allocator_summaries();
+        let allocator_rows: Vec<Row> = allocator_summaries
+            .iter()
+            .map(|summary| {
+                let row = Row::new(summary.ref_array());
+                if summary.is_fragmentation_suspect() {
+                    row.style(Style::default().fg(Color::Red))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        ret.tab_lists.insert(
+            SelectedTab::Allocators,
+            allocator_summaries
+                .into_iter()
+                .map(|summary| summary.name)
+                .collect(),
+        );
+
+        let allocators_header = AllocatorSummary::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.allocators_table = Table::new(
+            allocator_rows,
+            [
+                Constraint::Length(16),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(14),
+                Constraint::Length(14),
+                Constraint::Length(12),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(14),
+            ],
+        )
+        .header(allocators_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Allocators.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Allocators) {
+            if !ret.tab_lists[&SelectedTab::Allocators].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ret.sort_modules(ModuleSortKey::default());
+
+        ///////// Persistent terms
+
+        for info in ret.crash_dump.persistent_terms.iter_mut() {
+            if let InfoOrIndex::Info(info) = info {
+                info.terms.sort_by_key(|term| std::cmp::Reverse(term.size));
+            }
+        }
+        let persistent_terms: Vec<&PersistentTerm> = ret.persistent_terms().collect();
+        let persistent_term_rows: Vec<Row> = persistent_terms
+            .iter()
+            .map(|term| Row::new(term.ref_array(ret.crash_dump.preamble.word_size)))
+            .collect();
+        let persistent_term_keys: Vec<String> = persistent_terms
+            .iter()
+            .map(|term| term.decoded_key.to_string())
+            .collect();
+        ret.tab_lists.insert(SelectedTab::PersistentTerms, persistent_term_keys);
+
+        let persistent_terms_header = PersistentTerm::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.persistent_terms_table = Table::new(
+            persistent_term_rows,
+            [Constraint::Length(50), Constraint::Length(12), Constraint::Min(30)],
+        )
+        .header(persistent_terms_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::PersistentTerms.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::PersistentTerms) {
+            if !ret.tab_lists[&SelectedTab::PersistentTerms].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ///////// Timers
+
+        let mut timer_counts: HashMap<String, usize> = HashMap::new();
+        for timer in &ret.crash_dump.timers {
+            if let InfoOrIndex::Info(timer_info) = timer {
+                *timer_counts.entry(timer_info.pid.clone()).or_default() += 1;
+            }
+        }
+        // processes with the most pending timers first, as that's what a timer leak looks like
+        let mut timers: Vec<&TimerInfo> = ret
+            .crash_dump
+            .timers
+            .iter()
+            .filter_map(|timer| match timer {
+                InfoOrIndex::Info(timer_info) => Some(timer_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        timers.sort_by(|a, b| {
+            timer_counts[&b.pid]
+                .cmp(&timer_counts[&a.pid])
+                .then_with(|| a.pid.cmp(&b.pid))
+                .then_with(|| a.time_left.cmp(&b.time_left))
+        });
+        let timer_rows: Vec<Row> = timers
+            .iter()
+            .map(|timer| {
+                let name = match ret.crash_dump.processes.get(&timer.pid).as_deref() {
+                    Some(InfoOrIndex::Info(proc_info)) => proc_info.name.clone().unwrap_or_default(),
+                    _ => String::new(),
+                };
+                let [pid, time_left, message] = timer.ref_array();
+                Row::new(vec![pid, name, timer_counts[&timer.pid].to_string(), time_left, message])
+            })
+            .collect();
+        let timer_pids: Vec<String> = timers.iter().map(|timer| timer.pid.clone()).collect();
+        ret.tab_lists.insert(SelectedTab::Timers, timer_pids);
+
+        let timers_header = ["Pid", "Name", "Timers", "Time Left", "Message"]
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.timers_table = Table::new(
+            timer_rows,
+            [
+                Constraint::Length(15),
+                Constraint::Length(30),
+                Constraint::Length(8),
+                Constraint::Length(18),
+                Constraint::Min(30),
+            ],
+        )
+        .header(timers_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Timers.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Timers) {
+            if !ret.tab_lists[&SelectedTab::Timers].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ///////// Funs
+
+        // closures kept alive everywhere have the highest reference counts
+        ret.crash_dump.funs.sort_by(|a, b| {
+            b.refc
+                .cmp(&a.refc)
+                .then_with(|| (&a.module, a.index).cmp(&(&b.module, b.index)))
+        });
+        let fun_rows: Vec<Row> = ret
+            .crash_dump
+            .funs
+            .iter()
+            .map(|fun| Row::new(fun.ref_array()))
+            .collect();
+        let fun_modules: Vec<String> = ret
+            .crash_dump
+            .funs
+            .iter()
+            .map(|fun| fun.module.clone())
+            .collect();
+        ret.tab_lists.insert(SelectedTab::Funs, fun_modules);
+
+        let funs_header = FunInfo::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.funs_table = Table::new(
+            fun_rows,
+            [
+                Constraint::Length(50),
+                Constraint::Length(30),
+                Constraint::Length(8),
+                Constraint::Length(20),
+                Constraint::Length(8),
+            ],
+        )
+        .header(funs_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Funs.to_string()));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Funs) {
+            if !ret.tab_lists[&SelectedTab::Funs].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ///////// Distribution
+
+        ret.crash_dump.nodes.sort_by_key(|node| match node {
+            InfoOrIndex::Info(node_info) => {
+                (node_info.type_, node_info.channel.parse::<u64>().unwrap_or(u64::MAX))
+            }
+            InfoOrIndex::Index(_) => (NodeType::NotConnected, u64::MAX),
+        });
+        let node_rows: Vec<Row> = ret.nodes().map(|node| Row::new(node.ref_array())).collect();
+        let node_names: Vec<String> = ret.nodes().map(|node| node.name.clone()).collect();
+        ret.tab_lists.insert(SelectedTab::Distribution, node_names);
+
+        let distribution_header = NodeInfo::headers()
+            .into_iter()
+            .map(Cell::from)
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        ret.distribution_table = Table::new(
+            node_rows,
+            [
+                Constraint::Length(8),
+                Constraint::Length(30),
+                Constraint::Length(14),
+                Constraint::Length(18),
+                Constraint::Length(12),
+                Constraint::Length(8),
+                Constraint::Length(12),
+                Constraint::Length(14),
+            ],
+        )
+        .header(distribution_header)
+        .row_highlight_style(selected_row_style)
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title("Nodes"));
+
+        if let Some(state) = ret.table_states.get_mut(&SelectedTab::Distribution) {
+            if !ret.tab_lists[&SelectedTab::Distribution].is_empty() {
+                state.select(Some(0));
+            }
+        }
+
+        ret.filter_raw_sections(false);
 
         ret.inspect_scroll_state = ScrollViewState::default();
 
@@ -366,41 +929,590 @@ impl App<'_> {
         self.selected_tab = self.selected_tab.previous()
     }
 
-    pub fn get_heap_info(&self, pid: &str) -> io::Result<Text> {
-        self.parser
-            .get_heap_info(&self.crash_dump, &self.filepath, pid)
-    }
+    /// Sorts the ETS tables by `sort_key` (largest first) and rebuilds the ETS table widget.
+    pub fn sort_ets(&mut self, sort_key: EtsSortKey) {
+        self.ets_sort = sort_key;
+        let key = |ets: &InfoOrIndex<EtsInfo>| match ets {
+            InfoOrIndex::Info(ets_info) => match sort_key {
+                EtsSortKey::Words => ets_info.words,
+                EtsSortKey::Objects => ets_info.objects,
+            },
+            InfoOrIndex::Index(_) => 0,
+        };
+        self.crash_dump.ets.sort_by_key(|ets| std::cmp::Reverse(key(ets)));
+
+        let mut owners = Vec::with_capacity(self.crash_dump.ets.len());
+        let mut rows = Vec::with_capacity(self.crash_dump.ets.len());
+        for ets in &self.crash_dump.ets {
+            match ets {
+                InfoOrIndex::Info(ets_info) => {
+                    owners.push(ets_info.pid.clone());
+                    rows.push(Row::new(ets_info.ref_array(self.crash_dump.preamble.word_size)));
+                }
+                InfoOrIndex::Index(index_row) => {
+                    owners.push(index_row.id.clone().unwrap_or_default());
+                    rows.push(Row::new(vec![format!("Unparsed ETS table: {:?}", index_row.id)]));
+                }
+            }
+        }
+        self.tab_lists.insert(SelectedTab::Ets, owners);
 
-    pub fn get_stack_info(&self, pid: &str) -> io::Result<Text> {
-        self.parser
-            .get_stack_info(&self.crash_dump, &self.filepath, pid)
-    }
+        let header_style = Style::default().fg(Color::White).bg(Color::Red);
+        let sorted_header_style = Style::default().fg(Color::Yellow).bg(Color::Red);
+        let ets_header = EtsInfo::headers()
+            .into_iter()
+            .map(|header| {
+                let sorted = matches!(
+                    (sort_key, header),
+                    (EtsSortKey::Words, "Words") | (EtsSortKey::Objects, "Objects")
+                );
+                if sorted {
+                    Cell::from(format!("{} v", header)).style(sorted_header_style)
+                } else {
+                    Cell::from(header)
+                }
+            })
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
 
-    pub fn get_message_queue_info(&self, pid: &str) -> io::Result<Text> {
-        self.parser
-            .get_message_queue_info(&self.crash_dump, &self.filepath, pid)
-    }
-}
+        self.ets_table = Table::new(
+            rows,
+            [
+                Constraint::Length(15),
+                Constraint::Length(30),
+                Constraint::Length(40),
+                Constraint::Length(15),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(12),
+            ],
+        )
+        .header(ets_header)
+        .row_highlight_style(Style::default().fg(Color::White).bg(Color::Blue))
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(SelectedTab::Ets.to_string()));
 
-// Separated because this is the UI code. We need this here in order to render stuff *within* App state
-impl App<'_> {
-    pub fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
-        let titles = SelectedTab::iter().map(SelectedTab::title);
-        let highlight_style = (Color::default(), self.selected_tab.palette().c700);
-        let selected_tab_index = self.selected_tab as usize;
-        Tabs::new(titles)
-            .highlight_style(highlight_style)
-            .select(selected_tab_index)
-            .padding("", "")
-            .divider(" ")
-            .render(area, buf);
+        if let Some(state) = self.table_states.get_mut(&SelectedTab::Ets) {
+            if self.tab_lists[&SelectedTab::Ets].is_empty() {
+                state.select(None);
+            } else {
+                state.select(Some(0));
+            }
+        }
     }
 
-    pub fn get_selected_pid(&self) -> String {
+    pub fn sort_modules(&mut self, sort_key: ModuleSortKey) {
+        self.modules_sort = sort_key;
+        let modules = &mut self.crash_dump.loaded_modules.modules;
+        match sort_key {
+            ModuleSortKey::Name => modules.sort_by(|a, b| a.name.cmp(&b.name)),
+            ModuleSortKey::CurrentSize => {
+                modules.sort_by_key(|module| std::cmp::Reverse(module.current_size))
+            }
+            ModuleSortKey::OldSize => {
+                modules.sort_by_key(|module| std::cmp::Reverse(module.old_size.unwrap_or(-1)))
+            }
+        }
+
+        let names = modules.iter().map(|module| module.name.clone()).collect();
+        let rows: Vec<Row> = modules
+            .iter()
+            .map(|module| {
+                let row = Row::new(module.ref_array());
+                if module.has_old_code() {
+                    row.style(Style::default().fg(Color::Red))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        self.tab_lists.insert(SelectedTab::Modules, names);
+
+        let header_style = Style::default().fg(Color::White).bg(Color::Red);
+        let sorted_header_style = Style::default().fg(Color::Yellow).bg(Color::Red);
+        let modules_header = ModuleInfo::headers()
+            .into_iter()
+            .map(|header| {
+                let sorted = matches!(
+                    (sort_key, header),
+                    (ModuleSortKey::Name, "Module")
+                        | (ModuleSortKey::CurrentSize, "Current Size")
+                        | (ModuleSortKey::OldSize, "Old Size")
+                );
+                if sorted {
+                    Cell::from(format!("{} v", header)).style(sorted_header_style)
+                } else {
+                    Cell::from(header)
+                }
+            })
+            .collect::<Row>()
+            .style(header_style)
+            .height(1);
+
+        self.modules_table = Table::new(
+            rows,
+            [
+                Constraint::Length(40),
+                Constraint::Length(15),
+                Constraint::Length(15),
+                Constraint::Length(10),
+            ],
+        )
+        .header(modules_header)
+        .row_highlight_style(Style::default().fg(Color::White).bg(Color::Blue))
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(format!(
+            "{} (current code {}, old code {})",
+            SelectedTab::Modules,
+            human_byte_count(self.crash_dump.loaded_modules.current_code),
+            human_byte_count(self.crash_dump.loaded_modules.old_code)
+        )));
+
+        if let Some(state) = self.table_states.get_mut(&SelectedTab::Modules) {
+            if self.tab_lists[&SelectedTab::Modules].is_empty() {
+                state.select(None);
+            } else {
+                state.select(Some(0));
+            }
+        }
+    }
+
+    /// The processes running code in `module`, computing the module users on first use.
+    pub fn users_of_module(&mut self, module: &str) -> Vec<String> {
+        if self.module_users.is_none() {
+            let users = match self.parser.get_module_users(&mut self.crash_dump, &self.filepath) {
+                Ok(users) => users,
+                Err(err) => {
+                    self.crash_dump.errors.push(err);
+                    HashMap::new()
+                }
+            };
+            self.module_users = Some(users);
+        }
+        self.module_users
+            .as_ref()
+            .and_then(|users| users.get(module))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// The persistent terms, in the order of the Persistent Terms table.
+    pub fn persistent_terms(&self) -> impl Iterator<Item = &PersistentTerm> {
+        self.crash_dump
+            .persistent_terms
+            .iter()
+            .filter_map(|info| match info {
+                InfoOrIndex::Info(info) => Some(info.terms.iter()),
+                InfoOrIndex::Index(_) => None,
+            })
+            .flatten()
+    }
+
+    /// The nodes of the dump, in the order of the Distribution table.
+    pub fn nodes(&self) -> impl Iterator<Item = &NodeInfo> {
+        self.crash_dump.nodes.iter().filter_map(|node| match node {
+            InfoOrIndex::Info(node_info) => Some(node_info),
+            InfoOrIndex::Index(_) => None,
+        })
+    }
+
+    /// Lists the sections of the index by offset, only the ones with an unknown tag if
+    /// `unknown_only` is set.
+    pub fn filter_raw_sections(&mut self, unknown_only: bool) {
+        let mut sections: Vec<(Tag, IndexRow)> = self
+            .index_map
+            .iter()
+            .filter(|(tag, _)| !unknown_only || matches!(tag, Tag::Unknown(_)))
+            .flat_map(|(tag, index_value)| {
+                let rows: Vec<&IndexRow> = match index_value {
+                    IndexValue::Map(map) => map.values().collect(),
+                    IndexValue::List(list) => list.iter().collect(),
+                };
+                rows.into_iter().map(|row| (tag.clone(), row.clone()))
+            })
+            .collect();
+        sections.sort_by_key(|(_, row)| row.start.parse::<u64>().unwrap_or(0));
+
+        let labels = sections.iter().map(|(_, row)| row.start.clone()).collect();
+        self.tab_lists.insert(SelectedTab::RawSections, labels);
+        self.raw_sections_view.sections = sections;
+        self.raw_sections_view.unknown_only = unknown_only;
+        self.raw_sections_view.preview = None;
+
+        if let Some(state) = self.table_states.get_mut(&SelectedTab::RawSections) {
+            if self.tab_lists[&SelectedTab::RawSections].is_empty() {
+                state.select(None);
+            } else {
+                state.select(Some(0));
+            }
+        }
+    }
+
+    /// Loads the beginning of the selected raw section, unless it is already shown.
+    fn load_raw_section_preview(&mut self) {
+        let selected = self.table_states[&SelectedTab::RawSections].selected();
+        let Some((_, index_row)) = selected.and_then(|i| self.raw_sections_view.sections.get(i))
+        else {
+            self.raw_sections_view.preview = None;
+            return;
+        };
+        if matches!(&self.raw_sections_view.preview, Some((start, _)) if *start == index_row.start) {
+            return;
+        }
+        let contents = File::open(&self.filepath)
+            .and_then(|file| CrashDump::load_section_head(index_row, &file, RAW_SECTION_PREVIEW_LEN))
+            .unwrap_or_else(|err| format!("Failed to read the section: {}", err));
+        self.raw_sections_view.preview = Some((index_row.start.clone(), contents));
+    }
+
+    /// Streams the atoms the first time the Atoms tab is shown, counting them and detecting
+    /// atom families on the way.
+    pub fn load_atoms(&mut self) {
+        if self.atoms_view.loaded {
+            return;
+        }
+        self.atoms_view.loaded = true;
+
+        let file = match File::open(&self.filepath) {
+            Ok(file) => file,
+            Err(err) => {
+                self.atoms_view.error = Some(err.to_string());
+                return;
+            }
+        };
+        let re = Regex::new(&self.atoms_view.search).ok();
+        let mut total = 0;
+        let mut matches = vec![];
+        let families = AtomFamily::detect(
+            self.crash_dump
+                .stream_atoms(&file)
+                .map_while(Result::ok)
+                .inspect(|atom| {
+                    total += 1;
+                    if re.as_ref().is_none_or(|re| re.is_match(atom)) {
+                        matches.push(atom.clone());
+                    }
+                }),
+        );
+        self.atoms_view.total = total;
+        self.atoms_view.families = families;
+        self.set_atom_matches(matches);
+    }
+
+    /// Streams the atoms again, keeping the ones matching the search regex.
+    pub fn search_atoms(&mut self) {
+        self.atoms_view.editing = false;
+        let re = match Regex::new(&self.atoms_view.search) {
+            Ok(re) => re,
+            Err(err) => {
+                self.atoms_view.error = Some(err.to_string());
+                return;
+            }
+        };
+        self.atoms_view.error = None;
+        if !self.atoms_view.loaded {
+            self.load_atoms();
+            return;
+        }
+
+        let matches = match File::open(&self.filepath) {
+            Ok(file) => self
+                .crash_dump
+                .stream_atoms(&file)
+                .map_while(Result::ok)
+                .filter(|atom| re.is_match(atom))
+                .collect(),
+            Err(err) => {
+                self.atoms_view.error = Some(err.to_string());
+                return;
+            }
+        };
+        self.set_atom_matches(matches);
+    }
+
+    fn set_atom_matches(&mut self, atoms: Vec<String>) {
+        let empty = atoms.is_empty();
+        self.tab_lists.insert(SelectedTab::Atoms, atoms);
+        if let Some(state) = self.table_states.get_mut(&SelectedTab::Atoms) {
+            state.select(if empty { None } else { Some(0) });
+        }
+    }
+
+    /// Switches to the process tab with `pid` selected, if the process exists.
+    pub fn jump_to_process(&mut self, pid: &str) {
+        if let Some(position) = self.tab_lists[&SelectedTab::Process]
+            .iter()
+            .position(|p| p == pid)
+        {
+            if let Some(state) = self.table_states.get_mut(&SelectedTab::Process) {
+                state.select(Some(position));
+            }
+            self.selected_tab = SelectedTab::Process;
+        }
+    }
+
+    /// Jumps to the process behind the selected row: the owner of an ETS table, the
+    /// connected process of a port, the process running on a scheduler, the first
+    /// process running code in a module, or the receiver of a timer.
+    pub fn jump_to_selected_process(&mut self) {
+        let selected = self
+            .table_states
+            .get(&self.selected_tab)
+            .and_then(|state| state.selected());
+        let Some(key) = selected.and_then(|i| self.tab_lists[&self.selected_tab].get(i)) else {
+            return;
+        };
+        let pid = match self.selected_tab {
+            SelectedTab::Ets | SelectedTab::Timers => Some(key.clone()),
+            SelectedTab::Ports => match self.crash_dump.ports.get(key).as_deref() {
+                Some(InfoOrIndex::Info(port_info)) => Some(port_info.connected.clone()),
+                _ => None,
+            },
+            SelectedTab::Schedulers => self
+                .selected_scheduler()
+                .and_then(|scheduler| scheduler.current_process.as_ref())
+                .map(|current_process| current_process.pid.clone()),
+            SelectedTab::Modules => {
+                let module = key.clone();
+                self.users_of_module(&module).into_iter().next()
+            }
+            _ => None,
+        };
+        if let Some(pid) = pid {
+            self.jump_to_process(&pid);
+        }
+    }
+
+    /// The scheduler selected in the Schedulers tab. Rows follow the order of
+    /// `crash_dump.schedulers`, which is sorted when the table is built.
+    pub fn selected_scheduler(&self) -> Option<&SchedulerInfo> {
+        let selected = self.table_states[&SelectedTab::Schedulers].selected()?;
+        self.crash_dump
+            .schedulers
+            .iter()
+            .filter_map(|scheduler| match scheduler {
+                InfoOrIndex::Info(info) => Some(info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .nth(selected)
+    }
+
+    pub fn get_heap_info(&self, pid: &str) -> Result<(Text<'static>, ViewTerms), ParseError> {
+        self.parser
+            .get_heap_info(&self.crash_dump, &self.filepath, pid)
+            .map(|mut decoded| {
+                self.expand_terms(decoded.iter_mut().map(|term| &mut term.term));
+                (heap_text(&decoded), view_terms(decoded.iter().map(|term| &term.term)))
+            })
+    }
+
+    pub fn get_stack_info(&self, pid: &str) -> Result<(Text<'static>, ViewTerms), ParseError> {
+        self.parser
+            .get_stack_info(&self.crash_dump, &self.filepath, pid)
+            .map(|mut decoded| {
+                self.expand_terms(decoded.iter_mut().flat_map(|frame| frame.variables.iter_mut()));
+                let variables = decoded.iter().flat_map(|frame| frame.variables.iter());
+                (stack_text(&decoded), view_terms(variables))
+            })
+    }
+
+    pub fn get_message_queue_info(&self, pid: &str) -> Result<(Text<'static>, ViewTerms), ParseError> {
+        self.parser
+            .get_message_queue_info(&self.crash_dump, &self.filepath, pid)
+            .map(|mut decoded| {
+                self.expand_terms(decoded.iter_mut().map(|message| &mut message.message));
+                let messages = decoded.iter().map(|message| &message.message);
+                (message_queue_text(&decoded), view_terms(messages))
+            })
+    }
+
+    pub fn get_dictionary_info(&self, pid: &str) -> Result<(Text<'static>, ViewTerms), ParseError> {
+        self.parser
+            .get_dictionary_info(&self.crash_dump, &self.filepath, pid)
+            .map(|mut decoded| {
+                self.expand_terms(
+                    decoded
+                        .iter_mut()
+                        .flat_map(|entry| entry.key.iter_mut().chain([&mut entry.value])),
+                );
+                let terms = decoded.iter().flat_map(|entry| entry.key.iter().chain([&entry.value]));
+                (dictionary_text(&decoded), view_terms(terms))
+            })
+    }
+
+    // Decodes the truncated terms that were expanded, wherever they are in `terms`
+    fn expand_terms<'t>(&self, terms: impl Iterator<Item = &'t mut Term>) {
+        if self.term_expansion.expanded.is_empty() {
+            return;
+        }
+        let mut expanding = Vec::new();
+        terms.for_each(|term| expand_term(&self.crash_dump, &self.term_expansion.expanded, term, &mut expanding));
+    }
+
+    /// Decodes the stack, heap, message queue or dictionary of `pid`, whichever the process
+    /// view shows. If the section fails to decode, the error is shown instead and the section
+    /// can be opened in the raw section browser.
+    pub fn get_process_view_info(&mut self, pid: &str) -> (&'static str, Text<'static>) {
+        let (title, result) = match self.process_view_state {
+            ProcessViewState::Stack => ("Decoded Stack", self.get_stack_info(pid)),
+            ProcessViewState::Heap => ("Decoded Heap", self.get_heap_info(pid)),
+            ProcessViewState::MessageQueue => {
+                ("Decoded Message Queue", self.get_message_queue_info(pid))
+            }
+            ProcessViewState::Dictionary => {
+                ("Decoded Process Dictionary", self.get_dictionary_info(pid))
+            }
+        };
+        self.failed_section = result.as_ref().err().and_then(ParseError::offset);
+        let text = result.map(|(text, view_terms)| {
+            self.hex_view.binaries = view_terms.binaries;
+            self.term_expansion.truncated = view_terms.truncated;
+            text
+        });
+        let text = text.unwrap_or_else(|err| {
+            let mut lines = vec![Line::from(Span::styled(err.to_string(), Style::default().fg(Color::Red)))];
+            if self.failed_section.is_some() {
+                lines.push(Line::from("Press R to open the section in the raw section browser"));
+            }
+            Text::from(lines)
+        });
+        (title, text)
+    }
+
+    /// Chooses the next binary of the inspected view for the hex viewer, or the previous one
+    /// if `forward` is false, wrapping around. An open viewer shows the new one.
+    pub fn select_binary(&mut self, forward: bool) {
+        let count = self.hex_view.binaries.len();
+        if count == 0 {
+            return;
+        }
+        let selected = self.hex_view.selected.min(count - 1);
+        self.hex_view.selected = if forward {
+            (selected + 1) % count
+        } else {
+            (selected + count - 1) % count
+        };
+        if self.hex_view.contents.is_some() {
+            self.load_hex_view();
+        }
+    }
+
+    /// Opens the hex viewer on the chosen binary, or closes it if it is open.
+    pub fn toggle_hex_view(&mut self) {
+        if self.hex_view.contents.take().is_none() {
+            self.load_hex_view();
+        }
+    }
+
+    // Reads the chosen binary in full for the hex viewer
+    fn load_hex_view(&mut self) {
+        let Some(binary) = self.hex_view.binaries.get(self.hex_view.selected) else {
+            return;
+        };
+        let contents = self
+            .parser
+            .get_binary_contents(&self.crash_dump, &self.filepath, binary)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| contents.ok_or_else(|| "The dump has no =binary section for this binary".to_string()));
+        self.hex_view.contents = Some(contents);
+        self.hex_view.scroll = 0;
+    }
+
+    /// Chooses the next truncated term of the inspected view, or the previous one if `forward`
+    /// is false, wrapping around.
+    pub fn select_truncated(&mut self, forward: bool) {
+        let count = self.term_expansion.truncated.len();
+        if count == 0 {
+            return;
+        }
+        let selected = self.term_expansion.selected.min(count - 1);
+        self.term_expansion.selected = if forward {
+            (selected + 1) % count
+        } else {
+            (selected + count - 1) % count
+        };
+    }
+
+    /// Expands the chosen truncated term. The next one in the view is chosen once it is shown.
+    pub fn expand_truncated(&mut self) {
+        if let Some(data) = self.term_expansion.truncated.get(self.term_expansion.selected) {
+            self.term_expansion.expanded.insert(data.clone());
+        }
+    }
+
+    /// Scrolls the hex viewer by `lines`, up if negative.
+    pub fn scroll_hex_view(&mut self, lines: isize) {
+        let len = match &self.hex_view.contents {
+            Some(Ok(contents)) => contents.len(),
+            _ => 0,
+        };
+        let last_line = len.saturating_sub(1) / HEX_VIEW_LINE_LEN;
+        self.hex_view.scroll = self.hex_view.scroll.saturating_add_signed(lines).min(last_line);
+    }
+
+    /// Switches to the raw section browser with the section starting at `offset` selected.
+    pub fn jump_to_raw_section(&mut self, offset: u64) {
+        if self.raw_sections_view.unknown_only {
+            self.filter_raw_sections(false);
+        }
+        if let Some(position) = self
+            .raw_sections_view
+            .sections
+            .iter()
+            .position(|(_, row)| row.start.parse::<u64>().ok() == Some(offset))
+        {
+            if let Some(state) = self.table_states.get_mut(&SelectedTab::RawSections) {
+                state.select(Some(position));
+            }
+            self.selected_tab = SelectedTab::RawSections;
+        }
+    }
+
+    /// Selects the next section after the selected one that failed to load, wrapping around.
+    pub fn jump_to_next_failed_section(&mut self) {
+        let selected = self.table_states[&SelectedTab::RawSections].selected().unwrap_or(0);
+        let selected_offset = self
+            .raw_sections_view
+            .sections
+            .get(selected)
+            .and_then(|(_, row)| row.start.parse::<u64>().ok())
+            .unwrap_or(0);
+        let mut offsets: Vec<u64> = self.crash_dump.errors.iter().filter_map(ParseError::offset).collect();
+        offsets.sort_unstable();
+        if let Some(offset) = offsets
+            .iter()
+            .find(|offset| **offset > selected_offset)
+            .or(offsets.first())
+        {
+            self.jump_to_raw_section(*offset);
+        }
+    }
+}
+
+// Separated because this is the UI code. We need this here in order to render stuff *within* App state
+impl App<'_> {
+    pub fn render_tabs(&self, area: Rect, buf: &mut Buffer) {
+        let titles = SelectedTab::iter().map(SelectedTab::title);
+        let highlight_style = (Color::default(), self.selected_tab.palette().c700);
+        let selected_tab_index = self.selected_tab as usize;
+        Tabs::new(titles)
+            .highlight_style(highlight_style)
+            .select(selected_tab_index)
+            .padding("", "")
+            .divider(" ")
+            .render(area, buf);
+    }
+
+    pub fn get_selected_pid(&self) -> String {
         if self.selected_tab == SelectedTab::Process {
             let process_table_state = self.table_states.get(&SelectedTab::Process).unwrap();
             let selected_item = process_table_state.selected().unwrap_or(0);
-            self.tab_lists[&SelectedTab::Process][selected_item].clone()
+            self.tab_lists[&SelectedTab::Process]
+                .get(selected_item)
+                .cloned()
+                .unwrap_or_default()
         } else {
             String::new()
         }
@@ -416,15 +1528,30 @@ impl Widget for &mut App<'_> {
         let horizontal = Layout::horizontal([Min(0), Length(20)]);
         let [tabs_area, title_area] = horizontal.areas(header_area);
 
-        render_title(title_area, buf);
+        render_title(self.crash_dump.truncation.truncated, title_area, buf);
         self.render_tabs(tabs_area, buf);
         match self.selected_tab {
             SelectedTab::General => self.selected_tab.render_general(inner_area, buf, self),
             //SelectedTab::Index => self.selected_tab.render_index(inner_area, buf, self),
             SelectedTab::Process => self.selected_tab.render_process(inner_area, buf, self),
+            SelectedTab::Ets => self.selected_tab.render_ets(inner_area, buf, self),
+            SelectedTab::Ports => self.selected_tab.render_ports(inner_area, buf, self),
+            SelectedTab::Schedulers => self.selected_tab.render_schedulers(inner_area, buf, self),
+            SelectedTab::Allocators => self.selected_tab.render_allocators(inner_area, buf, self),
+            SelectedTab::Modules => self.selected_tab.render_modules(inner_area, buf, self),
+            SelectedTab::Funs => self.selected_tab.render_funs(inner_area, buf, self),
+            SelectedTab::Timers => self.selected_tab.render_timers(inner_area, buf, self),
+            SelectedTab::Atoms => self.selected_tab.render_atoms(inner_area, buf, self),
+            SelectedTab::PersistentTerms => self
+                .selected_tab
+                .render_persistent_terms(inner_area, buf, self),
+            SelectedTab::Distribution => self
+                .selected_tab
+                .render_distribution(inner_area, buf, self),
             SelectedTab::ProcessGroup => self
                 .selected_tab
                 .render_process_group(inner_area, buf, self),
+            SelectedTab::RawSections => self.selected_tab.render_raw_sections(inner_area, buf, self),
             SelectedTab::Inspect => self.selected_tab.render_inspect(inner_area, buf, self),
         }
         let footer_text = self
@@ -462,9 +1589,9 @@ impl SelectedTab {
 
     fn render_general(self, area: Rect, buf: &mut Buffer, app: &mut App) {
         let preamble_text = app.crash_dump.preamble.format();
-        let process_count = app.index_map[&Tag::Proc].len();
-        let ets_count = app.index_map[&Tag::Ets].len();
-        let fn_count = app.index_map[&Tag::Fun].len();
+        let process_count = app.index_map.get(&Tag::Proc).map_or(0, |v| v.len());
+        let ets_count = app.index_map.get(&Tag::Ets).map_or(0, |v| v.len());
+        let fn_count = app.index_map.get(&Tag::Fun).map_or(0, |v| v.len());
 
         let memory_info_text = app.crash_dump.memory.format();
 
@@ -509,12 +1636,162 @@ impl SelectedTab {
         general_info_text.extend(ets_count);
         general_info_text.extend(fn_count);
 
+        let truncation = &app.crash_dump.truncation;
+        if truncation.truncated {
+            let warning = Style::default().fg(Color::Red);
+            general_info_text.extend(vec![Line::from(Span::styled(
+                "Truncated Dump:",
+                warning.bold(),
+            ))]);
+            general_info_text.extend(vec![Line::from(Span::styled(
+                match &truncation.abort_reason {
+                    Some(reason) => format!("Aborted: {}", reason),
+                    None => "The dump has no =end section".to_string(),
+                },
+                warning,
+            ))]);
+            general_info_text.extend(vec![Line::from(Span::styled(
+                format!(
+                    "{} section(s) incomplete, missing fields are shown as empty or 0",
+                    truncation.incomplete_sections
+                ),
+                warning,
+            ))]);
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
         let paragraph = Paragraph::new(general_info_text)
             .block(Block::bordered().title("General Information"))
             .style(Style::default().fg(Color::White))
             .alignment(Alignment::Left);
 
-        Widget::render(&paragraph, area, buf);
+        Widget::render(&paragraph, layout[0], buf);
+
+        self.render_vm_internals(layout[1], buf, app);
+    }
+
+    fn render_vm_internals(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let header_style = Style::default().fg(Color::White).bg(Color::Blue);
+        let crash_dump = &app.crash_dump;
+
+        let mut index_tables: Vec<&IndexTableInfo> = crash_dump.index_tables.iter().collect();
+        index_tables.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut warnings: Vec<Line> = index_tables
+            .iter()
+            .filter(|table| table.is_near_limit())
+            .map(|table| {
+                Line::from(Span::styled(
+                    format!(
+                        "Warning: {} is over {:.0}% of its limit ({} of {})",
+                        table.name,
+                        INDEX_TABLE_WARNING_USAGE * 100.0,
+                        table.entries,
+                        table.limit
+                    ),
+                    Style::default().fg(Color::Red),
+                ))
+            })
+            .collect();
+        if warnings.is_empty() {
+            warnings.push(Line::from(Span::styled(
+                "No index table is near its limit",
+                Style::default().fg(Color::Green),
+            )));
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(warnings.len() as u16 + 2),
+                Constraint::Length(index_tables.len() as u16 + 3),
+                Constraint::Length(crash_dump.hash_tables.len() as u16 + 3),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let warnings_block = Paragraph::new(Text::from(warnings))
+            .block(Block::bordered().title("VM Internals"))
+            .style(Style::default().fg(Color::White));
+        Widget::render(&warnings_block, layout[0], buf);
+
+        let index_rows: Vec<Row> = index_tables
+            .iter()
+            .map(|table| {
+                let row = Row::new(table.ref_array());
+                if table.is_near_limit() {
+                    row.style(Style::default().fg(Color::Red))
+                } else {
+                    row
+                }
+            })
+            .collect();
+        let index_table = Table::new(
+            index_rows,
+            [
+                Constraint::Min(14),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            IndexTableInfo::headers()
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Row>()
+                .style(header_style),
+        )
+        .block(Block::bordered().title("Index Tables"));
+        Widget::render(&index_table, layout[1], buf);
+
+        let hash_rows: Vec<Row> = crash_dump
+            .hash_tables
+            .iter()
+            .map(|table| Row::new(table.ref_array()))
+            .collect();
+        let hash_table = Table::new(
+            hash_rows,
+            [
+                Constraint::Min(14),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(10),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            HashTableInfo::headers()
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Row>()
+                .style(header_style),
+        )
+        .block(Block::bordered().title("Hash Tables"));
+        Widget::render(&hash_table, layout[2], buf);
+
+        let area_rows: Vec<Row> = crash_dump
+            .allocated_areas
+            .iter()
+            .map(|allocated_area| Row::new(allocated_area.ref_array()))
+            .collect();
+        let areas_table = Table::new(
+            area_rows,
+            [Constraint::Min(20), Constraint::Length(14), Constraint::Length(14)],
+        )
+        .header(
+            AllocatedArea::headers()
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Row>()
+                .style(header_style),
+        )
+        .block(Block::bordered().title("Allocated Areas"));
+        Widget::render(&areas_table, layout[3], buf);
     }
 
     // fn render_index(self, area: Rect, buf: &mut Buffer, app: &mut App) {
@@ -558,18 +1835,30 @@ impl SelectedTab {
             );
         }
 
-        let selected_pid = &app.tab_lists[&SelectedTab::Process][selected_item];
+        // a truncated dump can have no processes at all
+        let selected_pid = &app.tab_lists[&SelectedTab::Process]
+            .get(selected_item)
+            .cloned()
+            .unwrap_or_default();
+        // the hex viewer closes outside the Inspector, and starts over for another process
+        if app.inspecting_pid != *selected_pid {
+            app.hex_view.selected = 0;
+            app.term_expansion = TermExpansion::default();
+        }
+        app.hex_view.contents = None;
+        app.inspecting_pid = selected_pid.clone();
+        let (inspect_info_title, inspect_info_text) = app.get_process_view_info(selected_pid);
         let selected_process_result = app.crash_dump.processes.get(selected_pid);
 
         let active_proc_info: types::ProcInfo;
-        let process_info_text: Text;
+        let mut process_info_text: Text;
         match selected_process_result {
             Some(process_ref) => {
                 let text = match *process_ref.value() {
                     InfoOrIndex::Info(ref proc_info) => {
                         let proc_info: &types::ProcInfo = proc_info;
                         active_proc_info = proc_info.clone();
-                        active_proc_info.format_as_ratatui_text()
+                        proc_info_text(&active_proc_info, app.crash_dump.preamble.word_size)
                     }
                     InfoOrIndex::Index(_) => {
                         Text::raw(format!("Index for pid: {:?}", selected_pid).to_string())
@@ -583,23 +1872,52 @@ impl SelectedTab {
             }
         };
 
-        let (inspect_info_title, inspect_info_text) = match app.process_view_state {
-            ProcessViewState::Stack => {
-                app.inspecting_pid = selected_pid.clone();
-                ("Decoded Stack", app.get_stack_info(selected_pid).unwrap())
+        // list the ports of the process right after its identity (pid, name, spawn info)
+        let ports = app.crash_dump.ports_for_process(selected_pid);
+        if !ports.is_empty() {
+            let mut port_lines = vec![Line::from(Span::styled(
+                "Ports: ",
+                Style::default().fg(Color::Yellow),
+            ))];
+            for port in ports {
+                port_lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", port.id), Style::default().fg(Color::Cyan)),
+                    Span::styled(port.controls, Style::default().fg(Color::White)),
+                ]));
             }
-            ProcessViewState::Heap => {
-                app.inspecting_pid = selected_pid.clone();
+            let position = process_info_text
+                .lines
+                .iter()
+                .position(|line| line.to_string().starts_with("Message Queue Length"))
+                .unwrap_or(process_info_text.lines.len());
+            process_info_text.lines.splice(position..position, port_lines);
+        }
 
-                ("Decoded Heap", app.get_heap_info(selected_pid).unwrap())
+        // and its pending timers, the ones firing first first
+        let timers = app.crash_dump.timers_for_process(selected_pid);
+        if !timers.is_empty() {
+            const MAX_TIMER_LINES: usize = 5;
+            let mut timer_lines = vec![Line::from(vec![
+                Span::styled("Timers: ", Style::default().fg(Color::Yellow)),
+                Span::styled(timers.len().to_string(), Style::default().fg(Color::Cyan)),
+            ])];
+            for timer in timers.iter().take(MAX_TIMER_LINES) {
+                timer_lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", timer.format_time_left()), Style::default().fg(Color::Cyan)),
+                    Span::styled(timer.decoded_message.to_string(), Style::default().fg(Color::White)),
+                ]));
             }
-            ProcessViewState::MessageQueue => {
-                app.inspecting_pid = selected_pid.clone();
-                (
-                "Decoded Message Queue",
-                app.get_message_queue_info(selected_pid).unwrap(),
-            )}
-        };
+            if timers.len() > MAX_TIMER_LINES {
+                timer_lines.push(Line::from(format!("  ... and {} more", timers.len() - MAX_TIMER_LINES)));
+            }
+            let position = process_info_text
+                .lines
+                .iter()
+                .position(|line| line.to_string().starts_with("Message Queue Length"))
+                .unwrap_or(process_info_text.lines.len());
+            process_info_text.lines.splice(position..position, timer_lines);
+        }
+
 
         //println!("heap info text: {}", heap_info_text);
 
@@ -618,6 +1936,540 @@ impl SelectedTab {
         Widget::render(&proc_heap, inner_layout[1], buf);
     }
 
+    fn render_ets(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let ets_table_state = app.table_states.get_mut(&SelectedTab::Ets).unwrap();
+        StatefulWidget::render(&app.ets_table, area, buf, ets_table_state);
+    }
+
+    fn render_ports(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let ports_table_state = app.table_states.get_mut(&SelectedTab::Ports).unwrap();
+        StatefulWidget::render(&app.ports_table, area, buf, ports_table_state);
+    }
+
+    fn render_schedulers(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let detail_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(layout[1]);
+
+        let schedulers_table_state = app.table_states.get_mut(&SelectedTab::Schedulers).unwrap();
+        StatefulWidget::render(&app.schedulers_table, layout[0], buf, schedulers_table_state);
+
+        let label = |s: &'static str| Span::styled(s, Style::default().fg(Color::Yellow));
+        let value = |s: String| Span::styled(s, Style::default().fg(Color::Cyan));
+
+        let mut detail_lines = vec![];
+        let mut stack_lines = vec![];
+        if let Some(scheduler) = app.selected_scheduler() {
+            detail_lines.push(Line::from(vec![
+                label("Scheduler: "),
+                value(format!("{} ({})", scheduler.id, scheduler.type_)),
+            ]));
+            detail_lines.push(Line::from(vec![
+                label("Sleep Info Flags: "),
+                value(scheduler.sleep_info.flags.join(" | ")),
+            ]));
+            detail_lines.push(Line::from(vec![
+                label("Sleep Info Aux Work: "),
+                value(scheduler.sleep_info.aux_work.join(" | ")),
+            ]));
+            detail_lines.push(Line::from(vec![
+                label("Run Queue Flags: "),
+                value(scheduler.run_queue.flags.join(" | ")),
+            ]));
+            detail_lines.push(Line::from(vec![
+                label("Current Port: "),
+                value(scheduler.current_port.clone()),
+            ]));
+            match &scheduler.current_process {
+                Some(current_process) => {
+                    detail_lines.push(Line::from(vec![
+                        label("Current Process: "),
+                        value(current_process.pid.clone()),
+                    ]));
+                    detail_lines.push(Line::from(vec![
+                        label("Current Process State: "),
+                        value(current_process.state.clone()),
+                    ]));
+                    detail_lines.push(Line::from(vec![
+                        label("Current Process Internal State: "),
+                        value(current_process.internal_state.join(" | ")),
+                    ]));
+                    detail_lines.push(Line::from(vec![
+                        label("Current Process Program Counter: "),
+                        value(current_process.program_counter.format()),
+                    ]));
+                    for frame in &current_process.stack_trace {
+                        let location = if frame.module.is_empty() {
+                            frame.function.clone()
+                        } else {
+                            format!("{}:{}/{} + {}", frame.module, frame.function, frame.arity, frame.offset)
+                        };
+                        stack_lines.push(Line::from(vec![
+                            Span::styled(format!("{} ", frame.address), Style::default().fg(Color::DarkGray)),
+                            value(location),
+                        ]));
+                        for variable in &frame.variables {
+                            stack_lines.push(Line::from(format!("    {}", variable)));
+                        }
+                    }
+                }
+                None => detail_lines.push(Line::from(vec![
+                    label("Current Process: "),
+                    value("none".to_string()),
+                ])),
+            }
+        }
+
+        // Dirty schedulers have no run queue of their own, so show the shared ones alongside
+        for dirty_run_queue in app.crash_dump.dirty_run_queues.iter() {
+            if let InfoOrIndex::Info(dirty_run_queue) = dirty_run_queue {
+                let run_queue = &dirty_run_queue.run_queue;
+                detail_lines.push(Line::from(vec![
+                    label("Run Queue "),
+                    label(if dirty_run_queue.type_ == SchedulerType::DirtyIo { "(dirty io): " } else { "(dirty cpu): " }),
+                    value(format!(
+                        "max {} high {} normal {} low {} port {}",
+                        run_queue.max_length,
+                        run_queue.high_length,
+                        run_queue.normal_length,
+                        run_queue.low_length,
+                        run_queue.port_length
+                    )),
+                ]));
+            }
+        }
+
+        let detail_block = Paragraph::new(Text::from(detail_lines))
+            .block(Block::bordered().title("Scheduler Details"))
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .alignment(Alignment::Left);
+
+        let stack_block = Paragraph::new(Text::from(stack_lines))
+            .block(Block::bordered().title("Current Process Stack Trace"))
+            .style(Style::default().fg(Color::White))
+            .alignment(Alignment::Left);
+
+        Widget::render(&detail_block, detail_layout[0], buf);
+        Widget::render(&stack_block, detail_layout[1], buf);
+    }
+
+    fn render_allocators(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+
+        let allocators_table_state = app.table_states.get_mut(&SelectedTab::Allocators).unwrap();
+        StatefulWidget::render(&app.allocators_table, layout[0], buf, allocators_table_state);
+
+        let selected_name = allocators_table_state
+            .selected()
+            .and_then(|i| app.tab_lists[&SelectedTab::Allocators].get(i));
+
+        // One line per instance of the selected allocator type
+        let mut instances: Vec<&AllocatorInfo> = app
+            .crash_dump
+            .allocators
+            .iter()
+            .filter_map(|allocator| match allocator {
+                InfoOrIndex::Info(info) if Some(&info.name) == selected_name => Some(info),
+                _ => None,
+            })
+            .collect();
+        instances.sort_by_key(|allocator| allocator.instance);
+
+        let mut lines = vec![];
+        for allocator in instances {
+            let utilization = allocator
+                .utilization()
+                .map_or("-".to_string(), |u| format!("{:.1}%", u * 100.0));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    match allocator.instance {
+                        Some(instance) => format!("{}[{}]: ", allocator.name, instance),
+                        None => format!("{}: ", allocator.name),
+                    },
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    format!(
+                        "carriers {} ({} mbcs, {} sbcs), blocks {}, utilization {}, calls alloc {} free {} realloc {}",
+                        human_byte_count(allocator.carriers_size()),
+                        allocator.mbcs_carriers.count[0],
+                        allocator.sbcs_carriers.count[0],
+                        human_byte_count(allocator.blocks_size()),
+                        utilization,
+                        allocator.calls.alloc,
+                        allocator.calls.free,
+                        allocator.calls.realloc,
+                    ),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]));
+        }
+
+        let detail_block = Paragraph::new(Text::from(lines))
+            .block(Block::bordered().title("Allocator Instances"))
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .alignment(Alignment::Left);
+
+        Widget::render(&detail_block, layout[1], buf);
+    }
+
+    fn render_raw_sections(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        app.load_raw_section_preview();
+
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(area);
+        let list_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                // warnings usually wrap onto a second line
+                Constraint::Length((app.crash_dump.warnings.len() + app.crash_dump.errors.len()).clamp(1, 4) as u16 * 2 + 2),
+                Constraint::Min(0),
+            ])
+            .split(layout[0]);
+
+        let warning_lines: Vec<Line> = if app.crash_dump.warnings.is_empty() && app.crash_dump.errors.is_empty() {
+            vec![Line::from(Span::styled("Every section tag is known", Style::default().fg(Color::Green)))]
+        } else {
+            let errors = app
+                .crash_dump
+                .errors
+                .iter()
+                .map(|error| Line::from(Span::styled(error.to_string(), Style::default().fg(Color::Red))));
+            app.crash_dump
+                .warnings
+                .iter()
+                .map(|warning| Line::from(Span::styled(warning.clone(), Style::default().fg(Color::Yellow))))
+                .chain(errors)
+                .collect()
+        };
+        let warnings_block = Paragraph::new(Text::from(warning_lines))
+            .block(Block::bordered().title("Warnings"))
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(Color::White));
+        Widget::render(&warnings_block, list_layout[0], buf);
+
+        // only build the rows that fit on screen
+        let sections = &app.raw_sections_view.sections;
+        let failed: HashSet<u64> = app.crash_dump.errors.iter().filter_map(ParseError::offset).collect();
+        let selected = app.table_states[&SelectedTab::RawSections].selected().unwrap_or(0);
+        let visible = list_layout[1].height.saturating_sub(3).max(1) as usize;
+        let first = (selected + 1).saturating_sub(visible);
+        let rows: Vec<Row> = sections
+            .iter()
+            .skip(first)
+            .take(visible)
+            .map(|(tag, row)| {
+                let (tag, style) = match tag {
+                    _ if row.start.parse().is_ok_and(|start: u64| failed.contains(&start)) => {
+                        (format!("{:?}", tag), Style::default().fg(Color::Red))
+                    }
+                    Tag::Unknown(name) => (name.clone(), Style::default().fg(Color::Yellow)),
+                    tag => (format!("{:?}", tag), Style::default()),
+                };
+                Row::new(vec![row.start.clone(), tag, row.id.clone().unwrap_or_default(), row.length.clone()])
+                    .style(style)
+            })
+            .collect();
+        let mut window_state = TableState::default();
+        if !sections.is_empty() {
+            window_state.select(Some(selected - first));
+        }
+        let title = if app.raw_sections_view.unknown_only {
+            format!("Unknown Sections ({})", sections.len())
+        } else {
+            format!("Sections ({})", sections.len())
+        };
+        let sections_table = Table::new(
+            rows,
+            [
+                Constraint::Length(12),
+                Constraint::Length(20),
+                Constraint::Min(16),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            ["Offset", "Tag", "Id", "Length"]
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Row>()
+                .style(Style::default().fg(Color::White).bg(Color::Red)),
+        )
+        .row_highlight_style(Style::default().fg(Color::White).bg(Color::Blue))
+        .highlight_spacing(HighlightSpacing::Always)
+        .block(Block::bordered().title(title));
+        StatefulWidget::render(&sections_table, list_layout[1], buf, &mut window_state);
+
+        let contents = app
+            .raw_sections_view
+            .preview
+            .as_ref()
+            .map_or("", |(_, contents)| contents.as_str());
+        let preview_block = Paragraph::new(contents)
+            .block(Block::bordered().title("Contents"))
+            .style(Style::default().fg(Color::White));
+        Widget::render(&preview_block, layout[1], buf);
+    }
+
+    fn render_funs(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let funs_table_state = app.table_states.get_mut(&SelectedTab::Funs).unwrap();
+        StatefulWidget::render(&app.funs_table, area, buf, funs_table_state);
+    }
+
+    fn render_timers(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let timers_table_state = app.table_states.get_mut(&SelectedTab::Timers).unwrap();
+        StatefulWidget::render(&app.timers_table, area, buf, timers_table_state);
+    }
+
+    fn render_atoms(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        app.load_atoms();
+
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(area);
+        let info_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(7), Constraint::Min(0)])
+            .split(layout[1]);
+
+        // there can be millions of atoms, so only build the rows that fit on screen
+        let atoms = &app.tab_lists[&SelectedTab::Atoms];
+        let selected = app.table_states[&SelectedTab::Atoms].selected().unwrap_or(0);
+        let visible = layout[0].height.saturating_sub(2).max(1) as usize;
+        let first = (selected + 1).saturating_sub(visible);
+        let rows: Vec<Row> = atoms
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .map(|(i, atom)| Row::new(vec![format!("{}", i + 1), atom.clone()]))
+            .collect();
+        let mut window_state = TableState::default();
+        if !atoms.is_empty() {
+            window_state.select(Some(selected - first));
+        }
+        let atoms_table = Table::new(rows, [Constraint::Length(10), Constraint::Min(20)])
+            .row_highlight_style(Style::default().fg(Color::White).bg(Color::Blue))
+            .highlight_spacing(HighlightSpacing::Always)
+            .block(Block::bordered().title(format!("Atoms ({} shown)", atoms.len())));
+        StatefulWidget::render(&atoms_table, layout[0], buf, &mut window_state);
+
+        let label = |s: &'static str| Span::styled(s, Style::default().fg(Color::Yellow));
+        let mut info_lines = vec![];
+        let count_line = match app.crash_dump.index_table("atom_tab") {
+            Some(atom_tab) => {
+                let usage = atom_tab.usage().unwrap_or(0.0);
+                let color = if atom_tab.is_near_limit() { Color::Red } else { Color::Cyan };
+                Line::from(vec![
+                    label("Atoms: "),
+                    Span::styled(
+                        format!("{} of {} ({:.1}% of the limit)", atom_tab.entries, atom_tab.limit, usage * 100.0),
+                        Style::default().fg(color),
+                    ),
+                ])
+            }
+            None => Line::from(vec![label("Atoms: "), Span::raw(app.atoms_view.total.to_string())]),
+        };
+        info_lines.push(count_line);
+        info_lines.push(Line::from(vec![
+            label("Atoms in dump: "),
+            Span::raw(app.atoms_view.total.to_string()),
+        ]));
+        let cursor = if app.atoms_view.editing { "_" } else { "" };
+        info_lines.push(Line::from(vec![
+            label("Search: "),
+            Span::styled(format!("{}{}", app.atoms_view.search, cursor), Style::default().fg(Color::Cyan)),
+        ]));
+        if let Some(error) = &app.atoms_view.error {
+            info_lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Red))));
+        }
+        let info_block = Paragraph::new(Text::from(info_lines))
+            .block(Block::bordered().title("Atom Table"))
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(Color::White));
+        Widget::render(&info_block, info_layout[0], buf);
+
+        let family_rows: Vec<Row> = app
+            .atoms_view
+            .families
+            .iter()
+            .map(|family| {
+                Row::new(vec![
+                    family.prefix.clone(),
+                    family.count.to_string(),
+                    format!("{}..{}", family.min_suffix, family.max_suffix),
+                ])
+            })
+            .collect();
+        let families_table = Table::new(
+            family_rows,
+            [Constraint::Min(20), Constraint::Length(10), Constraint::Length(24)],
+        )
+        .header(
+            ["Prefix", "Atoms", "Suffixes"]
+                .into_iter()
+                .map(Cell::from)
+                .collect::<Row>()
+                .style(Style::default().fg(Color::White).bg(Color::Red)),
+        )
+        .block(Block::bordered().title(format!(
+            "Atom Families ({} or more atoms with a numeric suffix)",
+            ATOM_FAMILY_MIN_SIZE
+        )));
+        Widget::render(&families_table, info_layout[1], buf);
+    }
+
+    fn render_persistent_terms(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+
+        let table_state = app.table_states.get_mut(&SelectedTab::PersistentTerms).unwrap();
+        StatefulWidget::render(&app.persistent_terms_table, layout[0], buf, table_state);
+
+        let selected = table_state.selected();
+        let text = match selected.and_then(|i| app.persistent_terms().nth(i)) {
+            Some(term) => Text::from(vec![
+                Line::from(vec![
+                    Span::styled("Key: ", Style::default().fg(Color::Yellow)),
+                    Span::styled(term.decoded_key.to_string(), Style::default().fg(Color::Cyan)),
+                ]),
+                Line::from(vec![
+                    Span::styled("Estimated Size: ", Style::default().fg(Color::Yellow)),
+                    Span::raw(format!(
+                        "{} ({} words)",
+                        human_bytes(term.size as i64, app.crash_dump.preamble.word_size),
+                        term.size
+                    )),
+                ]),
+                Line::from(Span::styled("Value: ", Style::default().fg(Color::Yellow))),
+                Line::from(term.decoded_value.to_string()),
+            ]),
+            None => Text::default(),
+        };
+        let detail_block = Paragraph::new(text)
+            .block(Block::bordered().title("Persistent Term"))
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .alignment(Alignment::Left);
+        Widget::render(&detail_block, layout[1], buf);
+    }
+
+    fn render_distribution(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(4),
+                Constraint::Percentage(50),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        let label = |s: &'static str| Span::styled(s, Style::default().fg(Color::Yellow));
+        let distribution = &app.crash_dump.distribution;
+        let state = if distribution.distributed {
+            Span::styled(
+                format!("distributed, {} nodes", app.tab_lists[&SelectedTab::Distribution].len()),
+                Style::default().fg(Color::Cyan),
+            )
+        } else {
+            Span::styled("not distributed", Style::default().fg(Color::Red))
+        };
+        let info_block = Paragraph::new(Text::from(vec![
+            Line::from(vec![label("Node: "), Span::raw(distribution.node_name.clone())]),
+            Line::from(vec![label("Distribution: "), state]),
+        ]))
+        .block(Block::bordered().title(SelectedTab::Distribution.to_string()))
+        .style(Style::default().fg(Color::White));
+        Widget::render(&info_block, layout[0], buf);
+
+        let table_state = app.table_states.get_mut(&SelectedTab::Distribution).unwrap();
+        StatefulWidget::render(&app.distribution_table, layout[1], buf, table_state);
+
+        let selected = table_state.selected();
+        let mut lines = vec![];
+        if let Some(node) = selected.and_then(|i| app.nodes().nth(i)) {
+            let sections = [
+                ("Remote Links (local, remote)", &node.remote_links),
+                ("Remote Monitoring (local, remote)", &node.remote_monitors),
+                ("Remotely Monitored By (local, remote)", &node.remotely_monitored_by),
+            ];
+            for (title, pairs) in sections {
+                lines.push(Line::from(Span::styled(title, Style::default().fg(Color::Yellow))));
+                for (local, remote) in pairs {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(local.clone(), Style::default().fg(Color::Cyan)),
+                        Span::raw(" - "),
+                        Span::raw(remote.clone()),
+                    ]));
+                }
+            }
+        }
+        let detail_block = Paragraph::new(Text::from(lines))
+            .block(Block::bordered().title("Node"))
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false });
+        Widget::render(&detail_block, layout[2], buf);
+    }
+
+    fn render_modules(self, area: Rect, buf: &mut Buffer, app: &mut App) {
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+
+        let modules_table_state = app.table_states.get_mut(&SelectedTab::Modules).unwrap();
+        StatefulWidget::render(&app.modules_table, layout[0], buf, modules_table_state);
+
+        let selected_module = modules_table_state
+            .selected()
+            .and_then(|i| app.tab_lists[&SelectedTab::Modules].get(i))
+            .cloned()
+            .unwrap_or_default();
+
+        let users = app.users_of_module(&selected_module);
+        let lines: Vec<Line> = users
+            .iter()
+            .map(|pid| {
+                let mut spans = vec![Span::styled(pid.clone(), Style::default().fg(Color::Yellow))];
+                if let Some(proc_ref) = app.crash_dump.processes.get(pid) {
+                    if let InfoOrIndex::Info(proc_info) = proc_ref.value() {
+                        if let Some(name) = &proc_info.name {
+                            spans.push(Span::styled(format!(" {}", name), Style::default().fg(Color::Cyan)));
+                        }
+                        if proc_info.program_counter.module == selected_module {
+                            spans.push(Span::raw(format!(" (executing {})", proc_info.program_counter.format())));
+                        }
+                    }
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        let users_block = Paragraph::new(Text::from(lines))
+            .block(Block::bordered().title(format!("Processes in {} ({})", selected_module, users.len())))
+            .style(Style::default().fg(Color::White))
+            .alignment(Alignment::Left);
+
+        Widget::render(&users_block, layout[1], buf);
+    }
+
     fn render_process_group(self, area: Rect, buf: &mut Buffer, app: &mut App) {
         let outer_layout = Layout::default()
             .direction(Direction::Horizontal)
@@ -636,7 +2488,11 @@ impl SelectedTab {
             .unwrap();
 
         let selected_item = group_table_state.selected().unwrap_or(0);
-        let selected_pid = &app.tab_lists[&SelectedTab::ProcessGroup][selected_item];
+        // a truncated dump can have no processes at all
+        let selected_pid = &app.tab_lists[&SelectedTab::ProcessGroup]
+            .get(selected_item)
+            .cloned()
+            .unwrap_or_default();
         let selected_process_result = app.crash_dump.processes.get(selected_pid);
 
         let active_proc_info: types::ProcInfo;
@@ -647,7 +2503,7 @@ impl SelectedTab {
                     InfoOrIndex::Info(ref proc_info) => {
                         let proc_info: &types::ProcInfo = proc_info;
                         active_proc_info = proc_info.clone();
-                        active_proc_info.format_as_ratatui_text()
+                        proc_info_text(&active_proc_info, app.crash_dump.preamble.word_size)
                     }
                     InfoOrIndex::Index(_) => {
                         Text::raw(format!("Index for pid: {:?}", selected_pid).to_string())
@@ -671,7 +2527,9 @@ impl SelectedTab {
                                 match *child_info_ref.value() {
                                     // Dereference the Ref
                                     InfoOrIndex::Info(ref proc_info) => {
-                                        Row::new(proc_info.summary_ref_array())
+                                        Row::new(proc_info.summary_ref_array(
+                                            app.crash_dump.preamble.word_size,
+                                        ))
                                     }
                                     InfoOrIndex::Index(_) => {
                                         Row::new(vec![format!("{:?}", child_pid)])
@@ -727,6 +2585,17 @@ impl SelectedTab {
     }
 
     fn render_inspect(self, area: Rect, buf: &mut Buffer, app: &mut App) {    
+        // the hex viewer takes the lower half when it is open
+        let (area, hex_area) = if app.hex_view.contents.is_some() {
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            (layout[0], Some(layout[1]))
+        } else {
+            (area, None)
+        };
+
         let width = if buf.area.height < 70 {
             buf.area.width - 1
         } else {
@@ -734,26 +2603,33 @@ impl SelectedTab {
         };
         let mut scroll_view = ScrollView::new(Size::new(width, 70));
 
-        let inspect_info_text;
-        let inspect_info_title;
-        {
-            let (t1, t2) = match app.process_view_state {
-                ProcessViewState::Stack => 
-                    {
-                        ("Decoded Stack", app.get_stack_info(&app.inspecting_pid).unwrap())
-                    },
-                ProcessViewState::Heap => ("Decoded Heap", app.get_heap_info(&app.inspecting_pid).unwrap()),
-                ProcessViewState::MessageQueue => (
-                    "Decoded Message Queue",
-                    app.get_message_queue_info(&app.inspecting_pid).unwrap(),
-                ),
-            };
-            inspect_info_title = t1;
-            inspect_info_text = t2.clone();
+        let inspecting_pid = app.inspecting_pid.clone();
+        let (inspect_info_title, inspect_info_text) = app.get_process_view_info(&inspecting_pid);
+
+        let mut block = Block::bordered().title(inspect_info_title);
+        let term_expansion = &mut app.term_expansion;
+        if !term_expansion.truncated.is_empty() {
+            term_expansion.selected = term_expansion.selected.min(term_expansion.truncated.len() - 1);
+            let term = app.crash_dump.decode(&term_expansion.truncated[term_expansion.selected]);
+            block = block.title(Line::from(format!(
+                " Truncated {}/{}: {} ",
+                term_expansion.selected + 1,
+                term_expansion.truncated.len(),
+                title_preview(term.to_string())
+            )).right_aligned());
+        }
+        let hex_view = &mut app.hex_view;
+        if !hex_view.binaries.is_empty() {
+            hex_view.selected = hex_view.selected.min(hex_view.binaries.len() - 1);
+            block = block.title(Line::from(format!(
+                " Binary {}/{}: {} ",
+                hex_view.selected + 1,
+                hex_view.binaries.len(),
+                title_preview(hex_view.binaries[hex_view.selected].to_string())
+            )).right_aligned());
         }
-
         let proc_info = Paragraph::new(inspect_info_text)
-        .block(Block::bordered().title(inspect_info_title))
+        .block(block)
         .style(Style::default().fg(Color::White))
         .wrap(Wrap { trim: false })
         .alignment(Alignment::Left);
@@ -761,6 +2637,24 @@ impl SelectedTab {
     
         proc_info.render(area, &mut scroll_view.buf_mut());
         scroll_view.render(area, buf, &mut app.inspect_scroll_state);
+
+        if let (Some(hex_area), Some(contents)) = (hex_area, &app.hex_view.contents) {
+            let hex_view = &app.hex_view;
+            let (title, text) = match contents {
+                Ok(contents) => (
+                    format!("Binary {}/{} ({} bytes)", hex_view.selected + 1, hex_view.binaries.len(), contents.len()),
+                    hex_text(contents, hex_view.scroll, hex_area.height.saturating_sub(2) as usize),
+                ),
+                Err(err) => (
+                    "Binary".to_string(),
+                    Text::from(Span::styled(err.clone(), Style::default().fg(Color::Red))),
+                ),
+            };
+            Paragraph::new(text)
+                .block(Block::bordered().title(title))
+                .style(Style::default().fg(Color::White))
+                .render(hex_area, buf);
+        }
     }
 
     const fn palette(self) -> tailwind::Palette {
@@ -768,14 +2662,474 @@ impl SelectedTab {
             Self::General => tailwind::BLUE,
             //Self::Index => tailwind::TEAL,
             Self::Process => tailwind::EMERALD,
+            Self::Ets => tailwind::AMBER,
+            Self::Ports => tailwind::CYAN,
+            Self::Schedulers => tailwind::ROSE,
+            Self::Allocators => tailwind::LIME,
+            Self::Modules => tailwind::SKY,
+            Self::Funs => tailwind::PINK,
+            Self::Timers => tailwind::ORANGE,
+            Self::Atoms => tailwind::FUCHSIA,
+            Self::PersistentTerms => tailwind::TEAL,
+            Self::Distribution => tailwind::VIOLET,
             Self::ProcessGroup => tailwind::INDIGO,
+            Self::RawSections => tailwind::STONE,
             Self::Inspect => tailwind::PURPLE,
         }
     }
 }
 
-fn render_title(area: Rect, buf: &mut Buffer) {
-    "ERL Crash Dump".render(area, buf);
+fn render_title(truncated: bool, area: Rect, buf: &mut Buffer) {
+    if truncated {
+        Span::styled("TRUNCATED DUMP", Style::default().fg(Color::Red).bold()).render(area, buf);
+    } else {
+        "ERL Crash Dump".render(area, buf);
+    }
+}
+
+fn proc_info_text(proc_info: &ProcInfo, word_size: u8) -> Text<'static> {
+    // format as a ratatui text, composed of different lines. Each value should have a colorized key and values
+    // key should be yellow, value should be cyan
+    let mut text = Text::default();
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Pid: ", Style::default().fg(Color::Yellow)),
+        Span::styled(proc_info.pid.clone(), Style::default().fg(Color::Cyan)),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("State: ", Style::default().fg(Color::Yellow)),
+        Span::styled(proc_info.state.clone(), Style::default().fg(Color::Cyan)),
+    ]));
+
+    if let Some(name) = &proc_info.name {
+        text.lines.push(Line::from(vec![
+            Span::styled("Name: ", Style::default().fg(Color::Yellow)),
+            Span::styled(name.clone(), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    if let Some(spawned_as) = &proc_info.spawned_as {
+        text.lines.push(Line::from(vec![
+            Span::styled("Spawned As: ", Style::default().fg(Color::Yellow)),
+            Span::styled(spawned_as.clone(), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    if let Some(spawned_by) = &proc_info.spawned_by {
+        text.lines.push(Line::from(vec![
+            Span::styled("Spawned By: ", Style::default().fg(Color::Yellow)),
+            Span::styled(spawned_by.clone(), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Message Queue Length: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{}", proc_info.message_queue_length),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled(
+            "Number of Heap Fragments: ",
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!("{}", proc_info.number_of_heap_fragments),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Heap Fragment Data: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{}", proc_info.heap_fragment_data),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Reductions: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{}", proc_info.reductions),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Stack Heap: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{}", proc_info.stack_heap),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Old Heap: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{} ({})", human_bytes(proc_info.old_heap, word_size), proc_info.old_heap),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Heap Unused: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(
+                "{} ({})",
+                human_bytes(proc_info.heap_unused, word_size),
+                proc_info.heap_unused
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Old Heap Unused: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(
+                "{} ({})",
+                human_bytes(proc_info.old_heap_unused, word_size),
+                proc_info.old_heap_unused
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Bin Vheap: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{} ({})", human_bytes(proc_info.bin_vheap, word_size), proc_info.bin_vheap),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Old Bin Vheap: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(
+                "{} ({})",
+                human_bytes(proc_info.old_bin_vheap, word_size),
+                proc_info.old_bin_vheap
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Bin Vheap Unused: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(
+                "{} ({})",
+                human_bytes(proc_info.bin_vheap_unused, word_size),
+                proc_info.bin_vheap_unused
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Old Bin Vheap Unused: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!(
+                "{} ({})",
+                human_bytes(proc_info.old_bin_vheap_unused, word_size),
+                proc_info.old_bin_vheap_unused
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Memory: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{} ({})", human_bytes(proc_info.memory, word_size), proc_info.memory),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Arity: ", Style::default().fg(Color::Yellow)),
+        Span::styled(format!("{}", proc_info.arity), Style::default().fg(Color::Cyan)),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Program Counter: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            proc_info.program_counter.format(),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        Span::styled("Internal State: ", Style::default().fg(Color::Yellow)),
+        Span::styled(
+            format!("{:?}", proc_info.internal_state),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    text
+}
+
+// Renders a term, colouring numbers, binaries, pids and what couldn't be decoded. Atoms and
+// the rest use `style`.
+fn term_spans(term: &Term, style: Style, spans: &mut Vec<Span<'static>>) {
+    fn punctuation(text: &'static str) -> Span<'static> {
+        Span::styled(text, Style::default().fg(Color::Gray))
+    }
+    fn elements_spans(terms: &[Term], style: Style, spans: &mut Vec<Span<'static>>) {
+        for (i, term) in terms.iter().enumerate() {
+            if i > 0 {
+                spans.push(punctuation(", "));
+            }
+            term_spans(term, style, spans);
+        }
+    }
+
+    if let Some(text) = term.as_string() {
+        spans.push(Span::styled(quote_string(&text), Style::default().fg(Color::LightGreen)));
+        return;
+    }
+    match term {
+        Term::Tuple(elements) => {
+            spans.push(punctuation("{"));
+            elements_spans(elements, style, spans);
+            spans.push(punctuation("}"));
+        }
+        Term::List(elements, tail) => {
+            spans.push(punctuation("["));
+            elements_spans(elements, style, spans);
+            if let Some(tail) = tail {
+                spans.push(punctuation(" | "));
+                term_spans(tail, style, spans);
+            }
+            spans.push(punctuation("]"));
+        }
+        Term::Map(pairs, rest) => {
+            spans.push(punctuation("#{"));
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                if i > 0 {
+                    spans.push(punctuation(", "));
+                }
+                term_spans(key, style, spans);
+                spans.push(punctuation(" => "));
+                term_spans(value, style, spans);
+            }
+            if let Some(rest) = rest {
+                if !pairs.is_empty() {
+                    spans.push(punctuation(", "));
+                }
+                term_spans(rest, style, spans);
+            }
+            spans.push(punctuation("}"));
+        }
+        Term::Integer(_) | Term::Bignum(_) | Term::Float(_) => {
+            spans.push(Span::styled(term.to_string(), Style::default().fg(Color::LightBlue)))
+        }
+        Term::Binary(_) => {
+            spans.push(Span::styled(term.to_string(), Style::default().fg(Color::Green)))
+        }
+        Term::Pid { node, .. } | Term::Port { node, .. } => {
+            spans.push(Span::styled(term.to_string(), Style::default().fg(Color::Magenta)));
+            // not part of the Erlang syntax, but the pid alone doesn't say which node it is on
+            if let Some(node) = node {
+                spans.push(Span::styled(format!(" on {}", node), Style::default().fg(Color::DarkGray)));
+            }
+        }
+        Term::Ref(_) => spans.push(Span::styled(term.to_string(), Style::default().fg(Color::Magenta))),
+        Term::Unresolved(_) | Term::Fun(FunTerm::Unresolved(_)) => spans.push(Span::styled(term.to_string(), Style::default().fg(Color::DarkGray))),
+        // underlined, as it can be expanded in the Inspector
+        Term::Truncated(_) => spans.push(Span::styled(term.to_string(), Style::default().fg(Color::DarkGray).underlined())),
+        Term::Atom(_) | Term::Fun(_) | Term::Raw(_) => spans.push(Span::styled(term.to_string(), style)),
+    }
+}
+
+// The binaries and truncated terms in `terms`, in the order they are printed
+fn view_terms<'t>(terms: impl Iterator<Item = &'t Term>) -> ViewTerms {
+    fn collect(term: &Term, view_terms: &mut ViewTerms) {
+        match term {
+            Term::Binary(binary) => view_terms.binaries.push(binary.clone()),
+            Term::Truncated(data) => view_terms.truncated.push(data.clone()),
+            Term::Tuple(elements) => elements.iter().for_each(|element| collect(element, view_terms)),
+            Term::List(elements, tail) => {
+                elements.iter().chain(tail.as_deref()).for_each(|element| collect(element, view_terms))
+            }
+            Term::Map(pairs, rest) => {
+                pairs.iter().for_each(|(key, value)| {
+                    collect(key, view_terms);
+                    collect(value, view_terms);
+                });
+                rest.iter().for_each(|rest| collect(rest, view_terms));
+            }
+            _ => {}
+        }
+    }
+
+    let mut view_terms = ViewTerms::default();
+    terms.for_each(|term| collect(term, &mut view_terms));
+    view_terms
+}
+
+// Replaces each truncated term in `term` that is in `expanded` with what it decodes to, and so
+// on for those truncated again inside it. `expanding` holds the ones being replaced, so that a
+// term truncated where it points back into itself is left as it is. A list whose tail was
+// truncated is joined with the rest of the list, and likewise for a map.
+fn expand_term(crash_dump: &CrashDump, expanded: &HashSet<String>, term: &mut Term, expanding: &mut Vec<String>) {
+    match term {
+        Term::Truncated(data) if expanded.contains(data) && !expanding.contains(data) => {
+            let data = data.clone();
+            *term = crash_dump.decode(&data);
+            expanding.push(data);
+            expand_term(crash_dump, expanded, term, expanding);
+            expanding.pop();
+        }
+        Term::Tuple(elements) => elements
+            .iter_mut()
+            .for_each(|element| expand_term(crash_dump, expanded, element, expanding)),
+        Term::List(elements, tail) => {
+            elements
+                .iter_mut()
+                .chain(tail.as_deref_mut())
+                .for_each(|element| expand_term(crash_dump, expanded, element, expanding));
+            if let Some(Term::List(rest, rest_tail)) = tail.as_deref_mut() {
+                let (rest, rest_tail) = (std::mem::take(rest), rest_tail.take());
+                elements.extend(rest);
+                *tail = rest_tail;
+            }
+        }
+        Term::Map(pairs, rest) => {
+            pairs.iter_mut().for_each(|(key, value)| {
+                expand_term(crash_dump, expanded, key, expanding);
+                expand_term(crash_dump, expanded, value, expanding);
+            });
+            if let Some(rest) = rest {
+                expand_term(crash_dump, expanded, rest, expanding);
+            }
+            if let Some(Term::Map(more, more_rest)) = rest.as_deref_mut() {
+                let (more, more_rest) = (std::mem::take(more), more_rest.take());
+                pairs.extend(more);
+                *rest = more_rest;
+            }
+        }
+        _ => {}
+    }
+}
+
+// `text` cut short to fit in the Inspector's title
+fn title_preview(text: String) -> String {
+    let mut preview: String = text.chars().take(INSPECT_TITLE_PREVIEW_LEN).collect();
+    if preview.len() < text.len() {
+        preview.push_str("...");
+    }
+    preview
+}
+
+// `height` lines of a hex dump of `bytes` from line `scroll`, with the offset, the bytes in hex
+// and the printable ASCII ones on each line
+fn hex_text(bytes: &[u8], scroll: usize, height: usize) -> Text<'static> {
+    let lines: Vec<Line> = bytes
+        .chunks(HEX_VIEW_LINE_LEN)
+        .enumerate()
+        .skip(scroll)
+        .take(height)
+        .map(|(i, chunk)| {
+            let hex: Vec<String> = (0..HEX_VIEW_LINE_LEN)
+                .map(|j| chunk.get(j).map_or("  ".to_string(), |byte| format!("{:02x}", byte)))
+                .collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' })
+                .collect();
+            Line::from(vec![
+                Span::styled(format!("{:08x}  ", i * HEX_VIEW_LINE_LEN), Style::default().fg(Color::Yellow)),
+                Span::raw(format!("{}  {}  ", hex[..8].join(" "), hex[8..].join(" "))),
+                Span::styled(format!("|{}|", ascii), Style::default().fg(Color::Green)),
+            ])
+        })
+        .collect();
+    Text::from(lines)
+}
+
+fn stack_text(frames: &[DecodedFrame]) -> Text<'static> {
+    let lines: Vec<Line> = frames
+        .iter()
+        .map(|frame| {
+            let mut spans = vec![
+                Span::styled(frame.address.clone(), Style::default().fg(Color::Yellow)),
+                Span::raw(" - M: "),
+                Span::styled(frame.module.clone(), Style::default().fg(Color::Green)),
+                Span::raw(" F: "),
+                Span::styled(frame.function.clone(), Style::default().fg(Color::Cyan)),
+                Span::raw(" A: ("),
+            ];
+            for (i, variable) in frame.variables.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(","));
+                }
+                term_spans(variable, Style::default().fg(Color::Magenta), &mut spans);
+            }
+            spans.push(Span::raw(")"));
+            Line::from(spans)
+        })
+        .collect();
+    Text::from(lines)
+}
+
+fn heap_text(terms: &[HeapTerm]) -> Text<'static> {
+    let lines: Vec<Line> = terms
+        .iter()
+        .map(|term| match (&term.address, &term.error) {
+            (Some(address), None) => {
+                let mut spans = vec![
+                    Span::styled(address.clone(), Style::default().fg(Color::Yellow)),
+                    Span::raw(" - "),
+                ];
+                term_spans(&term.term, Style::default().fg(Color::Cyan), &mut spans);
+                Line::from(spans)
+            }
+            (Some(address), Some(error)) => Line::from(vec![
+                Span::styled(address.clone(), Style::default().fg(Color::Yellow)),
+                Span::raw(" - "),
+                Span::styled(error.clone(), Style::default().fg(Color::Red)),
+            ]),
+            (None, _) => Line::from(term.term.to_string()),
+        })
+        .collect();
+    Text::from(lines)
+}
+
+fn message_queue_text(messages: &[DecodedMessage]) -> Text<'static> {
+    let lines: Vec<Line> = messages
+        .iter()
+        .map(|message| {
+            let mut spans = vec![
+                Span::styled(message.address.clone(), Style::default().fg(Color::Yellow)),
+                Span::raw(" - "),
+            ];
+            term_spans(&message.message, Style::default().fg(Color::Cyan), &mut spans);
+            Line::from(spans)
+        })
+        .collect();
+    Text::from(lines)
+}
+
+fn dictionary_text(entries: &[DictionaryEntry]) -> Text<'static> {
+    let lines: Vec<Line> = entries
+        .iter()
+        .map(|entry| {
+            let mut spans = vec![];
+            if let Some(key) = &entry.key {
+                term_spans(key, Style::default().fg(Color::Yellow), &mut spans);
+            }
+            spans.push(Span::raw(" => "));
+            term_spans(&entry.value, Style::default().fg(Color::Cyan), &mut spans);
+            Line::from(spans)
+        })
+        .collect();
+    Text::from(lines)
 }
 
 fn render_footer(footer_text: &str, area: Rect, buf: &mut Buffer) {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::app::{App, AppResult, ProcessViewState, SelectedTab};
+use crate::app::{App, AppResult, EtsSortKey, ModuleSortKey, ProcessViewState, SelectedTab};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 /// Handles the key events and updates the state of [`App`].
@@ -38,6 +38,27 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                 KeyCode::Right => app.next_tab(),
                 KeyCode::Left => app.prev_tab(),
         
+                KeyCode::Char('r') | KeyCode::Char('R') => {
+                    if let Some(offset) = app.failed_section {
+                        app.jump_to_raw_section(offset);
+                    }
+                }
+
+                KeyCode::Char('n') | KeyCode::Char('N') => app.select_binary(true),
+                KeyCode::Char('p') | KeyCode::Char('P') => app.select_binary(false),
+                KeyCode::Char('x') | KeyCode::Char('X') => app.toggle_hex_view(),
+                KeyCode::Char(']') => app.select_truncated(true),
+                KeyCode::Char('[') => app.select_truncated(false),
+                KeyCode::Char('e') | KeyCode::Char('E') => app.expand_truncated(),
+
+                // the hex viewer scrolls instead of the process info while it is open
+                KeyCode::Char('j') | KeyCode::Down if app.hex_view.contents.is_some() => app.scroll_hex_view(1),
+                KeyCode::Char('k') | KeyCode::Up if app.hex_view.contents.is_some() => app.scroll_hex_view(-1),
+                KeyCode::Char('f') | KeyCode::PageDown if app.hex_view.contents.is_some() => app.scroll_hex_view(16),
+                KeyCode::Char('b') | KeyCode::PageUp if app.hex_view.contents.is_some() => app.scroll_hex_view(-16),
+                KeyCode::Char('g') | KeyCode::Home if app.hex_view.contents.is_some() => app.scroll_hex_view(isize::MIN),
+                KeyCode::Char('G') | KeyCode::End if app.hex_view.contents.is_some() => app.scroll_hex_view(isize::MAX),
+
                 KeyCode::Char('j') | KeyCode::Down => app.inspect_scroll_state.scroll_down(),
                 KeyCode::Char('k') | KeyCode::Up => app.inspect_scroll_state.scroll_up(),
                 KeyCode::Char('f') | KeyCode::PageDown => app.inspect_scroll_state.scroll_page_down(),
@@ -48,6 +69,23 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                 _ => {}
             }
         }
+        SelectedTab::Atoms if app.atoms_view.editing => {
+            match key_event.code {
+                // Exit application on `Ctrl-C`
+                KeyCode::Char('c') | KeyCode::Char('C')
+                    if key_event.modifiers == KeyModifiers::CONTROL =>
+                {
+                    app.quit();
+                }
+                KeyCode::Char(c) => app.atoms_view.search.push(c),
+                KeyCode::Backspace => {
+                    app.atoms_view.search.pop();
+                }
+                KeyCode::Enter => app.search_atoms(),
+                KeyCode::Esc => app.atoms_view.editing = false,
+                _ => {}
+            }
+        }
         _ => {
             match key_event.code {
                 // Exit application on `ESC` or `q`
@@ -55,10 +93,10 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                     app.quit();
                 }
                 // Exit application on `Ctrl-C`
-                KeyCode::Char('c') | KeyCode::Char('C') => {
-                    if key_event.modifiers == KeyModifiers::CONTROL {
-                        app.quit();
-                    }
+                KeyCode::Char('c') | KeyCode::Char('C')
+                    if key_event.modifiers == KeyModifiers::CONTROL =>
+                {
+                    app.quit();
                 }
                 // Tab switching
                 KeyCode::Right => app.next_tab(),
@@ -95,11 +133,66 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
                     }
                 }
 
+                KeyCode::Char('d') | KeyCode::Char('D') if app.selected_tab == SelectedTab::Process => {
+                    app.process_view_state = ProcessViewState::Dictionary;
+                }
+
                 KeyCode::Char('i') | KeyCode::Char('I') => {
                     if app.selected_tab == SelectedTab::Process {
                         app.selected_tab = SelectedTab::Inspect;
                     }
                 }
+
+                KeyCode::Char('w') | KeyCode::Char('W') if app.selected_tab == SelectedTab::Ets => {
+                    app.sort_ets(EtsSortKey::Words);
+                }
+
+                KeyCode::Char('o') | KeyCode::Char('O') if app.selected_tab == SelectedTab::Ets => {
+                    app.sort_ets(EtsSortKey::Objects);
+                }
+
+                KeyCode::Char('n') | KeyCode::Char('N') if app.selected_tab == SelectedTab::Modules => {
+                    app.sort_modules(ModuleSortKey::Name);
+                }
+
+                KeyCode::Char('c') | KeyCode::Char('C') if app.selected_tab == SelectedTab::Modules => {
+                    app.sort_modules(ModuleSortKey::CurrentSize);
+                }
+
+                KeyCode::Char('o') | KeyCode::Char('O') if app.selected_tab == SelectedTab::Modules => {
+                    app.sort_modules(ModuleSortKey::OldSize);
+                }
+
+                KeyCode::Char('u') | KeyCode::Char('U') if app.selected_tab == SelectedTab::RawSections => {
+                    app.filter_raw_sections(!app.raw_sections_view.unknown_only);
+                }
+
+                KeyCode::Char('e') | KeyCode::Char('E') if app.selected_tab == SelectedTab::RawSections => {
+                    app.jump_to_next_failed_section();
+                }
+
+                KeyCode::Char('r') | KeyCode::Char('R') if app.selected_tab == SelectedTab::Process => {
+                    if let Some(offset) = app.failed_section {
+                        app.jump_to_raw_section(offset);
+                    }
+                }
+
+                KeyCode::Char('/') if app.selected_tab == SelectedTab::Atoms => {
+                    app.atoms_view.editing = true;
+                }
+
+                KeyCode::Enter
+                    if matches!(
+                        app.selected_tab,
+                        SelectedTab::Ets
+                            | SelectedTab::Ports
+                            | SelectedTab::Schedulers
+                            | SelectedTab::Modules
+                            | SelectedTab::Timers
+                    ) =>
+                {
+                    app.jump_to_selected_process();
+                }
         
                 KeyCode::Up => {
                     if let Some(table_state) = app.table_states.get_mut(&app.selected_tab) {
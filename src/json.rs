@@ -0,0 +1,325 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Non-interactive JSON output for `--action json`.
+//!
+//! This reuses the same two-phase parsing as the TUI (`CDParser::build_index` followed by
+//! `CDParser::parse`) and prints a single JSON document to stdout, so it can be consumed by
+//! scripts without a TTY. Progress and timing messages go to stderr.
+
+use crate::app::{report_load_problems, AppResult};
+use crate::parser::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// The JSON document printed by `--action json`.
+#[derive(Debug, Serialize)]
+pub struct JsonDump<'a> {
+    pub preamble: &'a Preamble,
+    pub memory: &'a MemoryInfo,
+    /// Every parsed process, sorted by pid.
+    pub processes: Vec<ProcInfo>,
+    /// Every parsed ETS table.
+    pub ets: Vec<&'a EtsInfo>,
+    /// Every parsed port, sorted by port id.
+    pub ports: Vec<PortInfo>,
+    /// Every parsed scheduler, normal ones first, each group sorted by id.
+    pub schedulers: Vec<&'a SchedulerInfo>,
+    /// Run queues shared by the dirty schedulers.
+    pub dirty_run_queues: Vec<&'a DirtyRunQueueInfo>,
+    /// Every parsed allocator instance, sorted by name and instance.
+    pub allocators: Vec<&'a AllocatorInfo>,
+    /// Allocators aggregated by type, with carrier utilization.
+    pub allocator_summaries: Vec<AllocatorSummary>,
+    /// Current and old code totals, and every loaded module.
+    pub loaded_modules: &'a LoadedModules,
+    /// The fun table, most referenced first.
+    pub funs: Vec<&'a FunInfo>,
+    /// Every pending timer, sorted by pid and time left.
+    pub timers: Vec<JsonTimer<'a>>,
+    /// Every persistent term with its decoded key and value, largest first.
+    pub persistent_terms: Vec<JsonPersistentTerm<'a>>,
+    /// Name of the crashed node and whether it was distributed.
+    pub distribution: &'a DistributionInfo,
+    /// Every connected and not connected node, by channel.
+    pub nodes: Vec<&'a NodeInfo>,
+    /// Every `=index_table` section, e.g. the atom table with its limit.
+    pub index_tables: &'a [IndexTableInfo],
+    /// Every `=hash_table` section, in dump order.
+    pub hash_tables: &'a [HashTableInfo],
+    /// The `=allocated_areas` section, largest area first.
+    pub allocated_areas: &'a [AllocatedArea],
+    /// Atoms sharing a prefix with numeric suffixes, largest family first.
+    pub atom_families: Vec<AtomFamily>,
+    /// Sections decoded by parsers registered with `register_section_parser`.
+    pub custom_sections: &'a [CustomSection],
+    /// Process groups keyed by the pid of the named ancestor.
+    pub group_info: BTreeMap<&'a str, &'a GroupInfo>,
+    /// Whether the dump was cut short, and how many sections are incomplete.
+    pub truncation: &'a TruncationInfo,
+    /// Problems found while loading the dump, e.g. unknown section tags.
+    pub warnings: &'a [String],
+    /// Sections that failed to load or decode, with where they are in the dump.
+    pub errors: Vec<JsonParseError>,
+    /// Number of sections in the dump for each tag.
+    pub section_counts: BTreeMap<String, usize>,
+}
+
+/// A `ParseError` as printed in the JSON document.
+#[derive(Debug, Serialize)]
+pub struct JsonParseError {
+    pub tag: Option<String>,
+    pub id: Option<String>,
+    /// Byte offset of the section header in the dump.
+    pub offset: Option<u64>,
+    pub cause: String,
+}
+
+impl From<&ParseError> for JsonParseError {
+    fn from(error: &ParseError) -> Self {
+        match error {
+            ParseError::Io(err) => JsonParseError {
+                tag: None,
+                id: None,
+                offset: None,
+                cause: err.to_string(),
+            },
+            ParseError::Section {
+                tag,
+                id,
+                offset,
+                cause,
+            } => JsonParseError {
+                tag: Some(format!("{:?}", tag)),
+                id: id.clone(),
+                offset: Some(*offset),
+                cause: cause.to_string(),
+            },
+        }
+    }
+}
+
+/// A `TimerInfo` as printed in the JSON document, with the decoded message as text.
+#[derive(Debug, Serialize)]
+pub struct JsonTimer<'a> {
+    pub pid: &'a str,
+    pub message: &'a str,
+    pub decoded_message: String,
+    pub time_left: i64,
+}
+
+impl<'a> From<&'a TimerInfo> for JsonTimer<'a> {
+    fn from(timer: &'a TimerInfo) -> Self {
+        JsonTimer {
+            pid: &timer.pid,
+            message: &timer.message,
+            decoded_message: timer.decoded_message.to_string(),
+            time_left: timer.time_left,
+        }
+    }
+}
+
+/// A `PersistentTerm` as printed in the JSON document, with the decoded key and value as text.
+#[derive(Debug, Serialize)]
+pub struct JsonPersistentTerm<'a> {
+    pub key: &'a str,
+    pub value: &'a str,
+    pub decoded_key: String,
+    pub decoded_value: String,
+    pub size: usize,
+}
+
+impl<'a> From<&'a PersistentTerm> for JsonPersistentTerm<'a> {
+    fn from(term: &'a PersistentTerm) -> Self {
+        JsonPersistentTerm {
+            key: &term.key,
+            value: &term.value,
+            decoded_key: term.decoded_key.to_string(),
+            decoded_value: term.decoded_value.to_string(),
+            size: term.size,
+        }
+    }
+}
+
+impl<'a> JsonDump<'a> {
+    pub fn new(
+        crash_dump: &'a CrashDump,
+        index_map: &IndexMap,
+        atom_families: Vec<AtomFamily>,
+    ) -> Self {
+        let mut processes: Vec<ProcInfo> = crash_dump
+            .processes
+            .iter()
+            .filter_map(|proc_ref| match proc_ref.value() {
+                InfoOrIndex::Info(proc_info) => Some(proc_info.clone()),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        processes.sort_by_cached_key(|proc_info| id_sort_key(&proc_info.pid));
+
+        let ets = crash_dump
+            .ets
+            .iter()
+            .filter_map(|ets| match ets {
+                InfoOrIndex::Info(ets_info) => Some(ets_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+
+        let mut ports: Vec<PortInfo> = crash_dump
+            .ports
+            .iter()
+            .filter_map(|port_ref| match port_ref.value() {
+                InfoOrIndex::Info(port_info) => Some(port_info.clone()),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        ports.sort_by_cached_key(|port_info| id_sort_key(&port_info.id));
+
+        let mut schedulers: Vec<&SchedulerInfo> = crash_dump
+            .schedulers
+            .iter()
+            .filter_map(|scheduler| match scheduler {
+                InfoOrIndex::Info(scheduler_info) => Some(scheduler_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        schedulers.sort_by_key(|scheduler| (scheduler.type_, scheduler.id));
+
+        let dirty_run_queues = crash_dump
+            .dirty_run_queues
+            .iter()
+            .filter_map(|run_queue| match run_queue {
+                InfoOrIndex::Info(run_queue_info) => Some(run_queue_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+
+        let mut allocators: Vec<&AllocatorInfo> = crash_dump
+            .allocators
+            .iter()
+            .filter_map(|allocator| match allocator {
+                InfoOrIndex::Info(allocator_info) => Some(allocator_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        allocators.sort_by(|a, b| (&a.name, a.instance).cmp(&(&b.name, b.instance)));
+
+        let mut funs: Vec<&FunInfo> = crash_dump.funs.iter().collect();
+        funs.sort_by_key(|fun| std::cmp::Reverse(fun.refc));
+
+        let mut timers: Vec<&TimerInfo> = crash_dump
+            .timers
+            .iter()
+            .filter_map(|timer| match timer {
+                InfoOrIndex::Info(timer_info) => Some(timer_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        timers.sort_by(|a, b| (&a.pid, a.time_left).cmp(&(&b.pid, b.time_left)));
+
+        let mut persistent_terms: Vec<&PersistentTerm> = crash_dump
+            .persistent_terms
+            .iter()
+            .filter_map(|info| match info {
+                InfoOrIndex::Info(info) => Some(info.terms.iter()),
+                InfoOrIndex::Index(_) => None,
+            })
+            .flatten()
+            .collect();
+        persistent_terms.sort_by_key(|term| std::cmp::Reverse(term.size));
+
+        let mut nodes: Vec<&NodeInfo> = crash_dump
+            .nodes
+            .iter()
+            .filter_map(|node| match node {
+                InfoOrIndex::Info(node_info) => Some(node_info),
+                InfoOrIndex::Index(_) => None,
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.channel.parse::<u64>().unwrap_or(u64::MAX));
+
+        let group_info = crash_dump
+            .group_info_map
+            .iter()
+            .map(|(pid, group)| (pid.as_str(), group))
+            .collect();
+
+        let section_counts = index_map
+            .iter()
+            .map(|(tag, index_value)| (format!("{:?}", tag), index_value.len()))
+            .collect();
+
+        JsonDump {
+            preamble: &crash_dump.preamble,
+            memory: &crash_dump.memory,
+            processes,
+            ets,
+            ports,
+            schedulers,
+            dirty_run_queues,
+            allocators,
+            allocator_summaries: crash_dump.allocator_summaries(),
+            loaded_modules: &crash_dump.loaded_modules,
+            funs,
+            timers: timers.into_iter().map(JsonTimer::from).collect(),
+            persistent_terms: persistent_terms
+                .into_iter()
+                .map(JsonPersistentTerm::from)
+                .collect(),
+            distribution: &crash_dump.distribution,
+            nodes,
+            index_tables: &crash_dump.index_tables,
+            hash_tables: &crash_dump.hash_tables,
+            allocated_areas: &crash_dump.allocated_areas,
+            atom_families,
+            custom_sections: &crash_dump.custom_sections,
+            group_info,
+            section_counts,
+            truncation: &crash_dump.truncation,
+            warnings: &crash_dump.warnings,
+            errors: crash_dump.errors.iter().map(JsonParseError::from).collect(),
+        }
+    }
+}
+
+/// Parses the crash dump at `filepath` and prints it as one JSON document to stdout.
+pub fn print_json(filepath: &str) -> AppResult<()> {
+    let parser = parser::CDParser::new(filepath)?;
+    let now = Instant::now();
+    let index_map = parser.build_index()?;
+    eprintln!("Building index took: {:.2?}", now.elapsed());
+    let now = Instant::now();
+    let mut crash_dump = parser.parse(&index_map)?;
+    eprintln!("Parsing everything took: {:.2?}", now.elapsed());
+    report_load_problems(&crash_dump);
+
+    let ancestor_map = parser::CDParser::create_descendants_table(&crash_dump.processes);
+    crash_dump.group_info_map =
+        parser::CDParser::calculate_group_info(&ancestor_map, &crash_dump.processes);
+
+    let file = File::open(filepath)?;
+    let atom_families = AtomFamily::detect(crash_dump.stream_atoms(&file).map_while(Result::ok));
+
+    let dump = JsonDump::new(&crash_dump, &index_map, atom_families);
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    serde_json::to_writer(&mut out, &dump)?;
+    writeln!(out)?;
+    Ok(())
+}
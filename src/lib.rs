@@ -0,0 +1,26 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Parser for Erlang crash dumps, usable without the terminal UI.
+//!
+//! A dump is parsed in two phases: `CDParser::build_index` finds the byte offset of every
+//! section, then `CDParser::parse` loads them into a `CrashDump`. Processes' stacks, heaps,
+//! message queues and dictionaries are only decoded on demand with `CDParser::get_stack_info`
+//! and friends, which return plain data such as `DecodedFrame` and `HeapTerm`. Decoded values
+//! are `Term`s, which print as Erlang syntax.
+//!
+//! The `crashdump_parser_cli` binary, built with the default `tui` feature, is one consumer of
+//! this library. Depend on it with `default-features = false` to leave out the UI dependencies.
+
+pub mod parser;
@@ -26,10 +26,13 @@ use crate::{
 pub mod app;
 pub mod event;
 pub mod handler;
-mod parser;
+pub mod json;
 pub mod tui;
 pub mod ui;
 
+// the UI modules refer to the parser as `crate::parser`
+use crashdump_parser::parser;
+
 use clap::Parser;
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -76,7 +79,7 @@ async fn main() -> AppResult<()> {
         // Exit the user interface.
         tui.exit()?;
     } else if args.action == "json" {
-        todo!()
+        json::print_json(&args.filepath)?;
     } else {
         println!("Invalid action: {}", args.action);
     }
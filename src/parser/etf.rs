@@ -0,0 +1,573 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+//
+// Decoder for the external term format, the encoding of `term_to_binary/1`.
+//
+// The dump writes some terms, mostly in the `=literals` and `=persistent_terms` sections, as
+// `E<size>:<base64>` where the base64 is a term in the external term format. `decode` turns
+// those bytes into a `Term`, resolving pids and refs to the channels the rest of the dump uses
+// and local funs through the fun table, so they print like terms in the heap encoding.
+//
+// See https://www.erlang.org/doc/apps/erts/erl_ext_dist.html for the format.
+//
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::term::{BinaryTerm, FunTerm, Term};
+use super::types::{hex_to_decimal, CrashDump, InfoOrIndex, SectionError};
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const BIT_BINARY_EXT: u8 = 77;
+const COMPRESSED: u8 = 80;
+const NEW_PID_EXT: u8 = 88;
+const NEW_PORT_EXT: u8 = 89;
+const NEWER_REFERENCE_EXT: u8 = 90;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const REFERENCE_EXT: u8 = 101;
+const PORT_EXT: u8 = 102;
+const PID_EXT: u8 = 103;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const NEW_FUN_EXT: u8 = 112;
+const EXPORT_EXT: u8 = 113;
+const NEW_REFERENCE_EXT: u8 = 114;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+const V4_PORT_EXT: u8 = 120;
+
+// Terms nested deeper than this are taken for corrupted data, as decoding them could overflow
+// the stack
+const MAX_DEPTH: usize = 1024;
+
+// Bytes reserved up front for a compressed term, whatever size it claims to inflate to
+const MAX_INFLATE_CAPACITY: usize = 1024 * 1024;
+
+/// Decodes `bytes`, a term in the external term format starting with its version byte.
+///
+/// Pids, ports and refs of the crashed node are printed with channel 0, those of other nodes
+/// with the channel of their `=node` section, or `?` if the dump has none.
+pub fn decode(crash_dump: &CrashDump, bytes: &[u8]) -> Result<Term, SectionError> {
+    let mut decoder = Decoder {
+        crash_dump,
+        bytes,
+        pos: 0,
+    };
+    let version = decoder.u8()?;
+    if version != VERSION {
+        return Err(SectionError::InvalidTerm(format!(
+            "Unsupported external term format version {}",
+            version
+        )));
+    }
+    let term = decoder.term(0)?;
+    if decoder.pos != bytes.len() {
+        return Err(SectionError::InvalidTerm(format!(
+            "{} trailing bytes after external term",
+            bytes.len() - decoder.pos
+        )));
+    }
+    Ok(term)
+}
+
+struct Decoder<'a> {
+    crash_dump: &'a CrashDump,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SectionError> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.pos..end))
+            .ok_or_else(|| {
+                SectionError::InvalidTerm(format!("External term ends early at byte {}", self.pos))
+            })?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, SectionError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, SectionError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, SectionError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, SectionError> {
+        Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn term(&mut self, depth: usize) -> Result<Term, SectionError> {
+        if depth > MAX_DEPTH {
+            return Err(SectionError::InvalidTerm(
+                "External term nested too deeply".to_string(),
+            ));
+        }
+        let depth = depth + 1;
+        let tag = self.u8()?;
+        match tag {
+            COMPRESSED => {
+                // the size comes from the data, so it is not trusted for the allocation, and no
+                // more than one byte past it is inflated
+                let size = self.u32()? as usize;
+                let mut inflated = Vec::with_capacity(size.min(MAX_INFLATE_CAPACITY));
+                ZlibDecoder::new(&self.bytes[self.pos..])
+                    .take(size as u64 + 1)
+                    .read_to_end(&mut inflated)
+                    .map_err(|e| {
+                        SectionError::InvalidTerm(format!(
+                            "Invalid compressed external term: {}",
+                            e
+                        ))
+                    })?;
+                if inflated.len() != size {
+                    return Err(SectionError::InvalidTerm(format!(
+                        "Compressed external term is {} bytes, expected {}",
+                        inflated.len(),
+                        size
+                    )));
+                }
+                self.pos = self.bytes.len();
+                let mut decoder = Decoder {
+                    crash_dump: self.crash_dump,
+                    bytes: &inflated,
+                    pos: 0,
+                };
+                let term = decoder.term(depth)?;
+                if decoder.pos != inflated.len() {
+                    return Err(SectionError::InvalidTerm(
+                        "Trailing bytes in compressed external term".to_string(),
+                    ));
+                }
+                Ok(term)
+            }
+            SMALL_INTEGER_EXT => Ok(Term::Integer(self.u8()? as i64)),
+            INTEGER_EXT => Ok(Term::Integer(self.u32()? as i32 as i64)),
+            NEW_FLOAT_EXT => Ok(Term::Float(f64::from_bits(self.u64()?))),
+            FLOAT_EXT => {
+                // a float printed with `%.20e`, padded with zeros to 31 bytes
+                let text = String::from_utf8_lossy(self.take(31)?);
+                let text = text.trim_end_matches('\0');
+                text.parse().map(Term::Float).map_err(|_| {
+                    SectionError::InvalidTerm(format!("Invalid float in external term: {}", text))
+                })
+            }
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                Ok(Term::Atom(self.atom_body(tag)?))
+            }
+            SMALL_TUPLE_EXT => {
+                let arity = self.u8()? as usize;
+                Ok(Term::Tuple(self.terms(arity, depth)?))
+            }
+            LARGE_TUPLE_EXT => {
+                let arity = self.u32()? as usize;
+                Ok(Term::Tuple(self.terms(arity, depth)?))
+            }
+            NIL_EXT => Ok(Term::List(vec![], None)),
+            STRING_EXT => {
+                // a list of small integers, such as a string
+                let len = self.u16()? as usize;
+                let elements = self
+                    .take(len)?
+                    .iter()
+                    .map(|byte| Term::Integer(*byte as i64))
+                    .collect();
+                Ok(Term::List(elements, None))
+            }
+            LIST_EXT => {
+                let len = self.u32()? as usize;
+                let elements = self.terms(len, depth)?;
+                let tail = match self.term(depth)? {
+                    Term::List(tail, None) if tail.is_empty() => None,
+                    tail => Some(Box::new(tail)),
+                };
+                Ok(Term::List(elements, tail))
+            }
+            BINARY_EXT => {
+                let len = self.u32()? as usize;
+                Ok(Term::Binary(BinaryTerm::Heap(self.take(len)?.to_vec())))
+            }
+            BIT_BINARY_EXT => {
+                // only the `bits` high bits of the last byte belong to the bitstring
+                let len = self.u32()? as usize;
+                let bits = self.u8()?;
+                if bits == 0 || bits > 8 {
+                    return Err(SectionError::InvalidTerm(format!(
+                        "Invalid bit count {} in external term",
+                        bits
+                    )));
+                }
+                let bytes = self.take(len)?.to_vec();
+                Ok(Term::Binary(BinaryTerm::Bits { bytes, bits }))
+            }
+            SMALL_BIG_EXT => {
+                let len = self.u8()? as usize;
+                self.big(len)
+            }
+            LARGE_BIG_EXT => {
+                let len = self.u32()? as usize;
+                self.big(len)
+            }
+            MAP_EXT => {
+                let arity = self.u32()? as usize;
+                let mut pairs = Vec::with_capacity(arity.min(self.bytes.len()));
+                for _ in 0..arity {
+                    let key = self.term(depth)?;
+                    let value = self.term(depth)?;
+                    pairs.push((key, value));
+                }
+                Ok(Term::Map(pairs, None))
+            }
+            PID_EXT | NEW_PID_EXT => {
+                let (channel, node) = self.node(depth)?;
+                let id = self.u32()?;
+                let serial = self.u32()?;
+                self.take(if tag == PID_EXT { 1 } else { 4 })?; // creation
+                Ok(Term::Pid {
+                    pid: format!("<{}.{}.{}>", channel, id, serial),
+                    node,
+                })
+            }
+            PORT_EXT | NEW_PORT_EXT | V4_PORT_EXT => {
+                let (channel, node) = self.node(depth)?;
+                let id = if tag == V4_PORT_EXT {
+                    self.u64()?
+                } else {
+                    self.u32()? as u64
+                };
+                self.take(if tag == PORT_EXT { 1 } else { 4 })?; // creation
+                Ok(Term::Port {
+                    port: format!("#Port<{}.{}>", channel, id),
+                    node,
+                })
+            }
+            REFERENCE_EXT => {
+                let (channel, _) = self.node(depth)?;
+                let id = self.u32()?;
+                self.u8()?; // creation
+                Ok(Term::Ref(format!("#Ref<{}.{}>", channel, id)))
+            }
+            NEW_REFERENCE_EXT | NEWER_REFERENCE_EXT => {
+                let len = self.u16()? as usize;
+                let (channel, _) = self.node(depth)?;
+                self.take(if tag == NEW_REFERENCE_EXT { 1 } else { 4 })?; // creation
+                let mut ids = Vec::with_capacity(len);
+                for _ in 0..len {
+                    ids.push(self.u32()?.to_string());
+                }
+                // printed most significant word first, as erlang:ref_to_list/1 does
+                ids.reverse();
+                Ok(Term::Ref(format!("#Ref<{}.{}>", channel, ids.join("."))))
+            }
+            NEW_FUN_EXT => {
+                // Size counts itself, and is used to skip the free variables
+                let start = self.pos;
+                let size = self.u32()? as usize;
+                let arity = self.u8()?;
+                self.take(16 + 4)?; // uniq (md5 of the module) and index
+                let num_free = self.u32()?;
+                let module = self.atom(depth)?;
+                let old_index = self.integer(depth)?;
+                let old_uniq = self.integer(depth)?;
+                self.term(depth)?; // pid of the creator
+                for _ in 0..num_free {
+                    self.term(depth)?;
+                }
+                if self.pos != start + size {
+                    return Err(SectionError::InvalidTerm(format!(
+                        "Invalid fun size {} in external term",
+                        size
+                    )));
+                }
+                Ok(Term::Fun(self.crash_dump.local_fun(
+                    &module,
+                    old_index,
+                    old_uniq,
+                    Some(arity as u32),
+                )))
+            }
+            EXPORT_EXT => {
+                let module = self.atom(depth)?;
+                let function = self.atom(depth)?;
+                let arity = self.integer(depth)?;
+                Ok(Term::Fun(FunTerm::External {
+                    module,
+                    function,
+                    arity,
+                }))
+            }
+            _ => Err(SectionError::InvalidTerm(format!(
+                "Unsupported external term tag {}",
+                tag
+            ))),
+        }
+    }
+
+    fn terms(&mut self, len: usize, depth: usize) -> Result<Vec<Term>, SectionError> {
+        // the length comes from the data, so it is not trusted for the allocation
+        let mut terms = Vec::with_capacity(len.min(self.bytes.len()));
+        for _ in 0..len {
+            terms.push(self.term(depth)?);
+        }
+        Ok(terms)
+    }
+
+    // The name of an atom of the given tag, after the tag byte
+    fn atom_body(&mut self, tag: u8) -> Result<String, SectionError> {
+        let len = match tag {
+            SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => self.u8()? as usize,
+            _ => self.u16()? as usize,
+        };
+        let bytes = self.take(len)?;
+        match tag {
+            // the deprecated atom tags are Latin-1
+            ATOM_EXT | SMALL_ATOM_EXT => Ok(bytes.iter().map(|byte| *byte as char).collect()),
+            _ => String::from_utf8(bytes.to_vec()).map_err(|_| {
+                SectionError::InvalidTerm("Invalid UTF-8 atom in external term".to_string())
+            }),
+        }
+    }
+
+    fn atom(&mut self, depth: usize) -> Result<String, SectionError> {
+        match self.term(depth)? {
+            Term::Atom(atom) => Ok(atom),
+            term => Err(SectionError::InvalidTerm(format!(
+                "Expected an atom in external term, got {}",
+                term
+            ))),
+        }
+    }
+
+    fn integer(&mut self, depth: usize) -> Result<i64, SectionError> {
+        match self.term(depth)? {
+            Term::Integer(integer) => Ok(integer),
+            term => Err(SectionError::InvalidTerm(format!(
+                "Expected an integer in external term, got {}",
+                term
+            ))),
+        }
+    }
+
+    // A sign byte, then `len` bytes of magnitude, least significant first
+    fn big(&mut self, len: usize) -> Result<Term, SectionError> {
+        let negative = self.u8()? != 0;
+        let digits = self.take(len)?;
+        if len <= 8 {
+            let mut magnitude = [0; 8];
+            magnitude[..len].copy_from_slice(digits);
+            let magnitude = u64::from_le_bytes(magnitude);
+            let integer = if negative {
+                0i64.checked_sub_unsigned(magnitude)
+            } else {
+                i64::try_from(magnitude).ok()
+            };
+            if let Some(integer) = integer {
+                return Ok(Term::Integer(integer));
+            }
+        }
+        let hex: String = digits
+            .iter()
+            .rev()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        let decimal = hex_to_decimal(&hex).unwrap_or_else(|| "0".to_string());
+        Ok(Term::Bignum(if negative {
+            format!("-{}", decimal)
+        } else {
+            decimal
+        }))
+    }
+
+    // The channel pids and refs of a node are printed with, and the node's name if it isn't the
+    // crashed node
+    fn node(&mut self, depth: usize) -> Result<(String, Option<String>), SectionError> {
+        let name = self.atom(depth)?;
+        let local = &self.crash_dump.distribution.node_name;
+        if name == "nonode@nohost" || name == *local {
+            return Ok(("0".to_string(), None));
+        }
+        let channel = self.crash_dump.nodes.iter().find_map(|node| match node {
+            InfoOrIndex::Info(node) if node.name == name => Some(node.channel.clone()),
+            _ => None,
+        });
+        Ok((channel.unwrap_or_else(|| "?".to_string()), Some(name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::prelude::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Term, SectionError> {
+        decode(&CrashDump::new(), bytes)
+    }
+
+    fn compressed(term: &[u8], size: u32) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(term).unwrap();
+        let mut bytes = vec![VERSION, COMPRESSED];
+        bytes.extend(size.to_be_bytes());
+        bytes.extend(encoder.finish().unwrap());
+        bytes
+    }
+
+    #[test]
+    fn small_big() {
+        let term = decode_bytes(&[131, SMALL_BIG_EXT, 2, 0, 0x00, 0x01]).unwrap();
+        assert_eq!(term, Term::Integer(256));
+        let min = [131, SMALL_BIG_EXT, 8, 1, 0, 0, 0, 0, 0, 0, 0, 0x80];
+        assert_eq!(decode_bytes(&min).unwrap(), Term::Integer(i64::MIN));
+        let max = [131, SMALL_BIG_EXT, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0x80];
+        assert_eq!(
+            decode_bytes(&max).unwrap(),
+            Term::Bignum("9223372036854775808".to_string())
+        );
+    }
+
+    #[test]
+    fn large_big() {
+        let bytes = [131, LARGE_BIG_EXT, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(
+            decode_bytes(&bytes).unwrap(),
+            Term::Bignum("-18446744073709551616".to_string())
+        );
+    }
+
+    #[test]
+    fn bit_binary() {
+        let bytes = [131, BIT_BINARY_EXT, 0, 0, 0, 2, 3, 1, 0b1110_0000];
+        let term = decode_bytes(&bytes).unwrap();
+        assert_eq!(
+            term,
+            Term::Binary(BinaryTerm::Bits {
+                bytes: vec![1, 0b1110_0000],
+                bits: 3
+            })
+        );
+        assert_eq!(term.to_string(), "<<1, 7:3>>");
+        let bytes = [131, BIT_BINARY_EXT, 0, 0, 0, 1, 8, 255];
+        assert_eq!(decode_bytes(&bytes).unwrap().to_string(), "<<255:8>>");
+    }
+
+    #[test]
+    fn bit_binary_with_invalid_bit_count() {
+        assert!(decode_bytes(&[131, BIT_BINARY_EXT, 0, 0, 0, 1, 9, 0]).is_err());
+        assert!(decode_bytes(&[131, BIT_BINARY_EXT, 0, 0, 0, 1, 0, 0]).is_err());
+        // reported rather than panicking, and kept as it is in the dump
+        let data = format!(
+            "E8:{}",
+            BASE64_STANDARD.encode([131, BIT_BINARY_EXT, 0, 0, 0, 1, 9, 0])
+        );
+        let crash_dump = CrashDump::new();
+        assert!(matches!(
+            crash_dump.try_decode(&data),
+            Err(SectionError::InvalidTerm(_))
+        ));
+        assert_eq!(crash_dump.decode(&data), Term::Raw(data));
+    }
+
+    #[test]
+    fn map() {
+        let bytes = [
+            131,
+            MAP_EXT,
+            0,
+            0,
+            0,
+            2,
+            SMALL_ATOM_UTF8_EXT,
+            1,
+            b'a',
+            SMALL_INTEGER_EXT,
+            1,
+            SMALL_ATOM_UTF8_EXT,
+            1,
+            b'b',
+            NIL_EXT,
+        ];
+        assert_eq!(
+            decode_bytes(&bytes).unwrap().to_string(),
+            "#{a => 1, b => []}"
+        );
+    }
+
+    #[test]
+    fn compressed_term() {
+        let tuple = [
+            SMALL_TUPLE_EXT,
+            2,
+            SMALL_INTEGER_EXT,
+            1,
+            SMALL_INTEGER_EXT,
+            2,
+        ];
+        let bytes = compressed(&tuple, tuple.len() as u32);
+        assert_eq!(decode_bytes(&bytes).unwrap().to_string(), "{1, 2}");
+    }
+
+    #[test]
+    fn compressed_term_with_wrong_size() {
+        let tuple = [
+            SMALL_TUPLE_EXT,
+            2,
+            SMALL_INTEGER_EXT,
+            1,
+            SMALL_INTEGER_EXT,
+            2,
+        ];
+        assert!(decode_bytes(&compressed(&tuple, tuple.len() as u32 + 1)).is_err());
+        assert!(decode_bytes(&compressed(&tuple, tuple.len() as u32 - 1)).is_err());
+        // a huge claimed size doesn't allocate it
+        assert!(decode_bytes(&compressed(&tuple, u32::MAX)).is_err());
+    }
+
+    #[test]
+    fn corrupt_input() {
+        // cut short, with trailing bytes, with another version and with an unknown tag
+        assert!(decode_bytes(&[131, SMALL_TUPLE_EXT, 2, SMALL_INTEGER_EXT, 1]).is_err());
+        assert!(decode_bytes(&[131, SMALL_INTEGER_EXT, 1, 0]).is_err());
+        assert!(decode_bytes(&[130, SMALL_INTEGER_EXT, 1]).is_err());
+        assert!(decode_bytes(&[131, 0]).is_err());
+        assert!(decode_bytes(&[]).is_err());
+        let bytes = [131, BINARY_EXT, 0xff, 0xff, 0xff, 0xff, 1];
+        assert!(decode_bytes(&bytes).is_err());
+    }
+}
@@ -12,6 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod etf;
 pub mod parser;
+pub mod term;
 pub mod types;
+pub mod walker;
+pub use self::term::*;
 pub use self::types::*;
+pub use self::walker::DecodeLimits;
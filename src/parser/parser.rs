@@ -22,14 +22,12 @@ use grep::{
     regex::RegexMatcher,
     searcher::{BinaryDetection, Searcher, SearcherBuilder, Sink, SinkMatch},
 };
-use ratatui::text::Text;
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::OpenOptions;
 use std::io::{self};
 use std::path::Path;
 use std::path::PathBuf;
-use std::time::Instant;
 
 struct IndexSink {
     matches: Vec<(Tag, Option<String>, u64)>,
@@ -50,15 +48,16 @@ impl Sink for IndexSink {
         let byte_offset = match_.absolute_byte_offset();
         let match_bytes = match_.bytes();
         if match_bytes.starts_with(b"=") {
+            let match_bytes = match_bytes.trim_ascii_end();
             let tag_end = match_bytes
                 .iter()
                 .position(|&x| x == b':')
-                .unwrap_or(match_bytes.len() - 1);
+                .unwrap_or(match_bytes.len());
 
             let tag = &match_bytes[1..tag_end];
 
-            // println!("tag: {:?}", std::str::from_utf8(tag).unwrap());
-            let tag_enum = types::string_tag_to_enum(std::str::from_utf8(tag).unwrap());
+            // a corrupted line may not be valid UTF-8, it then becomes an unknown tag
+            let tag_enum = types::string_tag_to_enum(&String::from_utf8_lossy(tag));
 
             let tag_id_string = if match_bytes.len() > tag_end + 1 {
                 let tag_id_cow = String::from_utf8_lossy(&match_bytes[tag_end + 1..]);
@@ -90,7 +89,7 @@ impl CDParser {
     /// # Errors
     ///
     /// Returns an error if the file cannot be opened or the path is invalid.
-    pub fn new(filepath: &str) -> Result<Self, io::Error> {
+    pub fn new(filepath: &str) -> Result<Self, ParseError> {
         let (filepath, filename) = Self::split_path_and_filename(filepath)?;
         let realpath = filepath.join(&filename);
 
@@ -110,10 +109,8 @@ impl CDParser {
     ///
     /// # Returns
     ///
-    /// Returns a `Result` containing the `IndexMap` if successful, or an `io::Error` if an error occurred during file processing.
-    pub fn build_index(&self) -> Result<IndexMap, io::Error> {
-        let now = Instant::now();
-
+    /// Returns a `Result` containing the `IndexMap` if successful, or a `ParseError` if an error occurred during file processing.
+    pub fn build_index(&self) -> Result<IndexMap, ParseError> {
         let matcher = RegexMatcher::new(r"^=.*").unwrap();
         let mut searcher = SearcherBuilder::new()
             .binary_detection(BinaryDetection::quit(b'\x00'))
@@ -132,24 +129,7 @@ impl CDParser {
                 start: offset1.to_string(),
                 length: (offset2 - offset1).to_string(),
             };
-            match tag_id {
-                Some(id) => {
-                    index_map
-                        .entry(*tag1)
-                        .or_insert_with(|| IndexValue::Map(HashMap::new()))
-                        .as_map_mut()
-                        .unwrap()
-                        .insert(id.clone(), index_row);
-                }
-                None => {
-                    index_map
-                        .entry(*tag1)
-                        .or_insert_with(|| IndexValue::List(Vec::new()))
-                        .as_list_mut()
-                        .unwrap()
-                        .push(index_row);
-                }
-            }
+            Self::insert_index_row(&mut index_map, tag1.clone(), index_row);
         }
         if let Some(last_match) = sink.matches.last() {
             let (last_tag, last_id, last_offset) = last_match;
@@ -159,32 +139,39 @@ impl CDParser {
                 start: last_offset.to_string(),
                 length: (file_size - last_offset).to_string(),
             };
-            match last_id {
-                Some(id) => {
-                    index_map
-                        .entry(*last_tag)
-                        .or_insert_with(|| IndexValue::Map(HashMap::new()))
-                        .as_map_mut()
-                        .unwrap()
-                        .insert(id.clone(), index_row);
-                }
-                None => {
-                    index_map
-                        .entry(*last_tag)
-                        .or_insert_with(|| IndexValue::List(Vec::new()))
-                        .as_list_mut()
-                        .unwrap()
-                        .push(index_row);
-                }
-            }
+            Self::insert_index_row(&mut index_map, last_tag.clone(), index_row);
         }
 
-        let elapsed = now.elapsed();
-        println!("Building index took: {:.2?}", elapsed);
-
         Ok(index_map)
     }
 
+    /// Inserts a section into the index.
+    ///
+    /// Sections with an id are keyed by that id, unless the tag allows several sections with the
+    /// same id (see `types::tag_has_repeated_ids`), in which case they are kept as a list like
+    /// sections without an id.
+    fn insert_index_row(index_map: &mut IndexMap, tag: Tag, index_row: IndexRow) {
+        match &index_row.id {
+            Some(id) if !types::tag_has_repeated_ids(&tag) => {
+                let id = id.clone();
+                index_map
+                    .entry(tag)
+                    .or_insert_with(|| IndexValue::Map(HashMap::new()))
+                    .as_map_mut()
+                    .unwrap()
+                    .insert(id, index_row);
+            }
+            _ => {
+                index_map
+                    .entry(tag)
+                    .or_insert_with(|| IndexValue::List(Vec::new()))
+                    .as_list_mut()
+                    .unwrap()
+                    .push(index_row);
+            }
+        }
+    }
+
     pub fn format_index(index_map: &IndexMap) -> Vec<String> {
         let mut formatted_index = Vec::new();
         for (tag, index_value) in index_map {
@@ -211,18 +198,18 @@ impl CDParser {
     }
 
     // after building the IndexMap, we can iterate through it and deserialize into the CrashDump struct
-    pub fn parse(&self, index_map: &IndexMap) -> io::Result<CrashDump> {
-        let crash_dump = CrashDump::from_index_map(&index_map, &self.filepath);
+    pub fn parse(&self, index_map: &IndexMap) -> Result<CrashDump, ParseError> {
+        let crash_dump = CrashDump::from_index_map(&index_map, &self.filepath)?;
 
-        crash_dump
+        Ok(crash_dump)
     }
 
-    pub fn get_heap_info<'a>(
+    pub fn get_heap_info(
         &self,
-        crash_dump: &'a CrashDump,
+        crash_dump: &CrashDump,
         filepath: &String,
         id: &str,
-    ) -> io::Result<Text<'a>> {
+    ) -> Result<Vec<HeapTerm>, ParseError> {
         // seeks to the file using the byteoffsets in the dict and just retrives the raw data
         //println!("{:?}", filepath);
         // println!("{:?}, {:#?}", id, crash_dump.processes_heap.get(id));
@@ -232,15 +219,15 @@ impl CDParser {
                 return crash_dump.load_proc_heap(heap_index, &file);
             }
         }
-        Ok(Text::from(""))
+        Ok(vec![])
     }
 
-    pub fn get_stack_info<'a>(
+    pub fn get_stack_info(
         &self,
-        crash_dump: &'a CrashDump,
+        crash_dump: &CrashDump,
         filepath: &String,
         id: &str,
-    ) -> io::Result<Text<'a>> {
+    ) -> Result<Vec<DecodedFrame>, ParseError> {
         if let Some(stack_info_ref) = crash_dump.processes_stack.get(id) {
             if let InfoOrIndex::Index(ref stack_index) = *stack_info_ref.value() {
                 let file = OpenOptions::new().read(true).open(filepath)?;
@@ -248,15 +235,44 @@ impl CDParser {
                 return crash_dump.load_proc_stack(stack_index, &file);
             }
         }
-        Ok(Text::from(""))
+        Ok(vec![])
+    }
+
+    pub fn get_dictionary_info(
+        &self,
+        crash_dump: &CrashDump,
+        filepath: &String,
+        id: &str,
+    ) -> Result<Vec<DictionaryEntry>, ParseError> {
+        if let Some(dictionary_index_ref) = crash_dump.processes_dictionary.get(id) {
+            if let InfoOrIndex::Index(ref dictionary_index) = *dictionary_index_ref.value() {
+                let file = OpenOptions::new().read(true).open(filepath)?;
+
+                return crash_dump.load_proc_dictionary(dictionary_index, &file);
+            }
+        }
+
+        Ok(vec![])
+    }
+
+    /// The stack sections that fail to load or parse are recorded in `crash_dump.errors`.
+    pub fn get_module_users(
+        &self,
+        crash_dump: &mut CrashDump,
+        filepath: &String,
+    ) -> Result<HashMap<String, Vec<String>>, ParseError> {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        let (users, errors) = crash_dump.module_users(&file);
+        crash_dump.errors.extend(errors);
+        Ok(users)
     }
 
-    pub fn get_message_queue_info<'a>(
+    pub fn get_message_queue_info(
         &self,
-        crash_dump: &'a CrashDump,
+        crash_dump: &CrashDump,
         filepath: &String,
         id: &str,
-    ) -> io::Result<Text<'a>> {
+    ) -> Result<Vec<DecodedMessage>, ParseError> {
         if let Some(mq_index_ref) = crash_dump.processes_messages.get(id) {
             if let InfoOrIndex::Index(ref mq_index) = *mq_index_ref.value() {
                 let file = OpenOptions::new().read(true).open(filepath)?;
@@ -265,7 +281,18 @@ impl CDParser {
             }
         }
 
-        Ok(Text::from(""))
+        Ok(vec![])
+    }
+
+    /// Reads the contents of a binary term, see `CrashDump::binary_contents`.
+    pub fn get_binary_contents(
+        &self,
+        crash_dump: &CrashDump,
+        filepath: &String,
+        binary: &BinaryTerm,
+    ) -> Result<Option<Vec<u8>>, ParseError> {
+        let file = OpenOptions::new().read(true).open(filepath)?;
+        crash_dump.binary_contents(binary, &file)
     }
 
     /// Calculates the group information for each process in the ancestor map.
@@ -378,3 +405,23 @@ impl CDParser {
         Ok((filepath.to_path_buf(), filename.into_owned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_twice() {
+        // the parser is a library: a process can load any number of dumps
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/sample_dumps/erl_crash_simple.dump"
+        );
+        for _ in 0..2 {
+            let parser = CDParser::new(path).unwrap();
+            let index_map = parser.build_index().unwrap();
+            let crash_dump = parser.parse(&index_map).unwrap();
+            assert_eq!(crash_dump.preamble.word_size, 8);
+        }
+    }
+}
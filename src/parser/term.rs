@@ -0,0 +1,609 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+//
+// Erlang terms decoded from the crash dump's heap encoding.
+//
+// `CrashDump::decode` builds a `Term` from an encoded term such as `t2:A2:ok,H14B3B28E0`,
+// following heap pointers through the addresses of the `=proc_heap` and `=literals` sections.
+// The `Display` implementation is the plain text renderer; the TUI has its own, which colours
+// the parts of a term.
+//
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A decoded Erlang term.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub enum Term {
+    Atom(String),
+    Integer(i64),
+    /// An integer too large for an `i64`, as its exact decimal digits with a leading `-` if
+    /// negative
+    Bignum(String),
+    Float(f64),
+    Tuple(Vec<Term>),
+    /// The elements of a list, and its tail if the list is improper
+    List(Vec<Term>, Option<Box<Term>>),
+    /// The key-value pairs of a map, and the rest of it if the heap walker left it truncated
+    Map(Vec<(Term, Term)>, Option<Box<Term>>),
+    Binary(BinaryTerm),
+    /// A pid, with the name of its node if it lives on a remote one
+    Pid {
+        pid: String,
+        node: Option<String>,
+    },
+    /// A port, with the name of its node if it lives on a remote one
+    Port {
+        port: String,
+        node: Option<String>,
+    },
+    Ref(String),
+    Fun(FunTerm),
+    /// A pointer to a heap address the dump doesn't have
+    Unresolved(String),
+    /// Part of a term left undecoded once the heap walker spent its budget, or a pointer back
+    /// into the term, in the heap encoding so it can be decoded on its own. Printed as `...`
+    Truncated(String),
+    /// Text that isn't a term in the heap encoding, e.g. a term the dump prints in Erlang syntax
+    Raw(String),
+}
+
+/// A binary, which the heap encoding only gives the bytes of when it is on the process heap.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub enum BinaryTerm {
+    /// The bytes of a binary stored on the process heap
+    Heap(Vec<u8>),
+    /// A bitstring, whose last byte only holds its `bits` high bits
+    Bits { bytes: Vec<u8>, bits: u8 },
+    /// A reference-counted binary, with the length of the binary and the first bytes of the part
+    /// referred to if the dump has its `=binary` section
+    Refc {
+        address: usize,
+        offset: usize,
+        size: usize,
+        len: Option<usize>,
+        bytes: Option<Vec<u8>>,
+    },
+    /// A sub binary of another binary
+    Sub {
+        address: usize,
+        offset: usize,
+        size: usize,
+        len: Option<usize>,
+        bytes: Option<Vec<u8>>,
+    },
+}
+
+/// A fun, local or external.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub enum FunTerm {
+    /// A local fun, `#Fun<Module.Index.Uniq>`, with its reference count from the fun table
+    Local {
+        module: String,
+        index: i64,
+        uniq: i64,
+        arity: Option<u32>,
+        refc: Option<i64>,
+    },
+    /// `fun Module:Function/Arity`
+    External {
+        module: String,
+        function: String,
+        arity: i64,
+    },
+    /// A fun referred to by the address of its entry in the emulator. The `=fun` sections don't
+    /// print that address, so the fun can't be resolved. Printed as `#Fun<unresolved 0x...>`
+    Unresolved(String),
+}
+
+// Writes `items` separated by `, `
+fn write_separated<T: fmt::Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", item)?;
+    }
+    Ok(())
+}
+
+// Bytes shown of a binary that isn't printed as a string
+const BINARY_PREVIEW_LEN: usize = 32;
+
+// Whether `char` is printable as in `io_lib:printable_unicode_list/1`, where the escapes for
+// whitespace and a few other control characters count as printable
+fn is_printable(char: char) -> bool {
+    matches!(
+        char,
+        ' '..='~'
+            | '\u{a0}'..='\u{d7ff}'
+            | '\u{e000}'..='\u{fffd}'
+            | '\u{10000}'..
+            | '\n'
+            | '\r'
+            | '\t'
+            | '\u{b}'
+            | '\u{8}'
+            | '\u{c}'
+            | '\u{1b}'
+    )
+}
+
+/// Quotes `text` as an Erlang string, escaping quotes, backslashes and control characters.
+pub fn quote_string(text: &str) -> String {
+    quote(text, '"')
+}
+
+// Words that are only atoms when quoted
+const RESERVED_WORDS: [&str; 29] = [
+    "after", "and", "andalso", "band", "begin", "bnot", "bor", "bsl", "bsr", "bxor", "case",
+    "catch", "cond", "div", "else", "end", "fun", "if", "let", "maybe", "not", "of", "or",
+    "orelse", "receive", "rem", "try", "when", "xor",
+];
+
+/// Writes `atom` as Erlang does, quoting it unless it starts with a lowercase letter and
+/// continues with letters, digits, `_` and `@`.
+pub fn quote_atom(atom: &str) -> String {
+    let mut chars = atom.chars();
+    let unquoted = chars.next().is_some_and(|char| char.is_ascii_lowercase())
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '@')
+        && !RESERVED_WORDS.contains(&atom);
+    if unquoted {
+        atom.to_string()
+    } else {
+        quote(atom, '\'')
+    }
+}
+
+// Surrounds `text` with `quote_char`, escaping it, backslashes and control characters
+fn quote(text: &str, quote_char: char) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push(quote_char);
+    for char in text.chars() {
+        match char {
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\u{b}' => quoted.push_str("\\v"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\u{1b}' => quoted.push_str("\\e"),
+            _ if char == quote_char => {
+                quoted.push('\\');
+                quoted.push(char);
+            }
+            _ => quoted.push(char),
+        }
+    }
+    quoted.push(quote_char);
+    quoted
+}
+
+impl Term {
+    /// The text of a list of printable characters, which Erlang prints as a string. The empty
+    /// list is printed as `[]`, so it isn't one.
+    pub fn as_string(&self) -> Option<String> {
+        match self {
+            Term::List(elements, None) if !elements.is_empty() => elements
+                .iter()
+                .map(|element| match element {
+                    Term::Integer(code) => u32::try_from(*code)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .filter(|char| is_printable(*char)),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+impl Default for Term {
+    /// An empty `Term::Raw`, for a term that hasn't been decoded yet.
+    fn default() -> Self {
+        Term::Raw(String::new())
+    }
+}
+
+impl BinaryTerm {
+    /// Size of the binary, or of the part of it a sub binary refers to, in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            BinaryTerm::Heap(bytes) | BinaryTerm::Bits { bytes, .. } => bytes.len(),
+            BinaryTerm::Refc { size, .. } | BinaryTerm::Sub { size, .. } => *size,
+        }
+    }
+
+    /// The bytes decoded with the term: all of a heap binary, or the first bytes of a
+    /// ref-counted or sub binary if the dump has its `=binary` section.
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            BinaryTerm::Heap(bytes) | BinaryTerm::Bits { bytes, .. } => Some(bytes),
+            BinaryTerm::Refc { bytes, .. } | BinaryTerm::Sub { bytes, .. } => bytes.as_deref(),
+        }
+    }
+
+    /// The text of a binary holding printable UTF-8, which Erlang prints as `<<"...">>`. A
+    /// bitstring isn't one, though its whole bytes can be printed as a string.
+    pub fn as_string(&self) -> Option<&str> {
+        if let BinaryTerm::Bits { .. } = self {
+            return None;
+        }
+        self.bytes()
+            .filter(|bytes| !bytes.is_empty() && bytes.len() == self.size())
+            .and_then(|bytes| std::str::from_utf8(bytes).ok())
+            .filter(|text| text.chars().all(is_printable))
+    }
+}
+
+// Writes a binary of `size` bytes starting with `bytes`, as a string if they are printable
+// UTF-8 and otherwise as its first `BINARY_PREVIEW_LEN` bytes. A binary cut short ends with
+// `...` and its size, and a bitstring with its trailing bits as `Value:Bits`.
+fn write_binary(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[u8],
+    size: usize,
+    trailing: Option<(u8, u8)>,
+) -> fmt::Result {
+    let bytes = &bytes[..bytes.len().min(size)];
+    let complete = bytes.len() == size;
+    // the first bytes can end in the middle of a character
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text),
+        Err(e) if !complete && e.error_len().is_none() => {
+            std::str::from_utf8(&bytes[..e.valid_up_to()]).ok()
+        }
+        Err(_) => None,
+    }
+    .filter(|text| !text.is_empty() && text.chars().all(is_printable));
+
+    write!(f, "<<")?;
+    let shown = match text {
+        Some(text) => {
+            write!(f, "{}", quote_string(text))?;
+            text.len()
+        }
+        None => {
+            let shown = bytes.len().min(BINARY_PREVIEW_LEN);
+            write_separated(f, &bytes[..shown])?;
+            if shown > 0 && shown < size {
+                write!(f, ", ")?;
+            }
+            shown
+        }
+    };
+    if shown < size {
+        write!(f, "... ({} bytes)", size)?;
+    }
+    if let Some((value, bits)) = trailing {
+        if size > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}:{}", value, bits)?;
+    }
+    write!(f, ">>")
+}
+
+// Formats a float the way Erlang prints it, with the fewest digits that read back as the same
+// float, e.g. `0.1`, `100.0` or `1.0e-16`. The exponent form is only used when it is shorter.
+fn format_float(float: f64) -> String {
+    if float == 0.0 {
+        return if float.is_sign_negative() {
+            "-0.0"
+        } else {
+            "0.0"
+        }
+        .to_string();
+    }
+    if !float.is_finite() {
+        return float.to_string();
+    }
+    // `{:e}` gives the shortest digits that round-trip, as in `1.2345e-5`
+    let scientific = format!("{:e}", float.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let exponent: i64 = exponent.parse().unwrap_or(0);
+    let sign = if float < 0.0 { "-" } else { "" };
+
+    // the float is 0.<digits> * 10^place
+    let place = exponent + 1;
+    let len = digits.len() as i64;
+    if place > 0 && place < len {
+        let (int, frac) = digits.split_at(place as usize);
+        return format!("{}{}.{}", sign, int, frac);
+    }
+    let exponent_form = if len == 1 {
+        format!("{}.0e{}", digits, exponent)
+    } else {
+        format!("{}.{}e{}", &digits[..1], &digits[1..], exponent)
+    };
+    let positional = if place <= 0 {
+        format!("0.{}{}", "0".repeat(-place as usize), digits)
+    } else {
+        format!("{}{}.0", digits, "0".repeat((place - len) as usize))
+    };
+    if positional.len() <= exponent_form.len() {
+        format!("{}{}", sign, positional)
+    } else {
+        format!("{}{}", sign, exponent_form)
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Atom(atom) => write!(f, "{}", quote_atom(atom)),
+            Term::Integer(integer) => write!(f, "{}", integer),
+            Term::Bignum(bignum) => write!(f, "{}", bignum),
+            Term::Float(float) => write!(f, "{}", format_float(*float)),
+            Term::Tuple(elements) => {
+                write!(f, "{{")?;
+                write_separated(f, elements)?;
+                write!(f, "}}")
+            }
+            Term::List(elements, tail) => {
+                if let Some(text) = self.as_string() {
+                    return write!(f, "{}", quote_string(&text));
+                }
+                write!(f, "[")?;
+                write_separated(f, elements)?;
+                if let Some(tail) = tail {
+                    write!(f, " | {}", tail)?;
+                }
+                write!(f, "]")
+            }
+            Term::Map(pairs, rest) => {
+                write!(f, "#{{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} => {}", key, value)?;
+                }
+                if let Some(rest) = rest {
+                    if !pairs.is_empty() {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", rest)?;
+                }
+                write!(f, "}}")
+            }
+            Term::Binary(binary) => write!(f, "{}", binary),
+            Term::Pid { pid, .. } | Term::Port { port: pid, .. } => write!(f, "{}", pid),
+            Term::Ref(reference) => write!(f, "{}", reference),
+            Term::Fun(fun) => write!(f, "{}", fun),
+            Term::Unresolved(address) => write!(f, "*U - {}", address),
+            Term::Truncated(_) => write!(f, "..."),
+            Term::Raw(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl fmt::Display for BinaryTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryTerm::Heap(bytes) => write_binary(f, bytes, bytes.len(), None),
+            BinaryTerm::Bits { bytes, bits } => match bytes.split_last() {
+                Some((last, bytes)) => {
+                    let value = last.checked_shr(8 - *bits as u32).unwrap_or(0);
+                    write_binary(f, bytes, bytes.len(), Some((value, *bits)))
+                }
+                None => write!(f, "<<>>"),
+            },
+            BinaryTerm::Refc {
+                bytes: Some(bytes),
+                size,
+                ..
+            }
+            | BinaryTerm::Sub {
+                bytes: Some(bytes),
+                size,
+                ..
+            } => write_binary(f, bytes, *size, None),
+            BinaryTerm::Refc {
+                address,
+                offset,
+                size,
+                ..
+            } => write!(
+                f,
+                "[ref-counted binary: binp0=0x{:x}, offset={}, sz={}, not found]",
+                address, offset, size
+            ),
+            BinaryTerm::Sub {
+                address,
+                offset,
+                size,
+                ..
+            } => write!(
+                f,
+                "[sub binary: binp0=0x{:x}, offset={}, sz={}, not found]",
+                address, offset, size
+            ),
+        }
+    }
+}
+
+impl fmt::Display for FunTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FunTerm::Local {
+                module,
+                index,
+                uniq,
+                arity,
+                refc,
+            } => {
+                write!(f, "#Fun<{}.{}.{}>", quote_atom(module), index, uniq)?;
+                let details: Vec<String> = arity
+                    .map(|arity| format!("arity {}", arity))
+                    .into_iter()
+                    .chain(refc.map(|refc| format!("refc {}", refc)))
+                    .collect();
+                if !details.is_empty() {
+                    write!(f, " ({})", details.join(", "))?;
+                }
+                Ok(())
+            }
+            FunTerm::External {
+                module,
+                function,
+                arity,
+            } => write!(
+                f,
+                "fun {}:{}/{}",
+                quote_atom(module),
+                quote_atom(function),
+                arity
+            ),
+            FunTerm::Unresolved(address) => write!(f, "#Fun<unresolved 0x{}>", address),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquoted_atom() {
+        assert_eq!(Term::Atom("ok".to_string()).to_string(), "ok");
+        assert_eq!(
+            Term::Atom("node@host_1".to_string()).to_string(),
+            "node@host_1"
+        );
+    }
+
+    #[test]
+    fn quoted_atom() {
+        assert_eq!(Term::Atom("EXIT".to_string()).to_string(), "'EXIT'");
+        assert_eq!(Term::Atom("".to_string()).to_string(), "''");
+        assert_eq!(Term::Atom("a b".to_string()).to_string(), "'a b'");
+        assert_eq!(Term::Atom("scc-sp".to_string()).to_string(), "'scc-sp'");
+        assert_eq!(Term::Atom("it's\n".to_string()).to_string(), "'it\\'s\\n'");
+        assert_eq!(Term::Atom("receive".to_string()).to_string(), "'receive'");
+    }
+
+    #[test]
+    fn remote_pid() {
+        let pid = Term::Pid {
+            pid: "<1234.80.0>".to_string(),
+            node: Some("b@host".to_string()),
+        };
+        assert_eq!(pid.to_string(), "<1234.80.0>");
+    }
+
+    #[test]
+    fn format_float_shortest() {
+        assert_eq!(format_float(100.0), "100.0");
+        assert_eq!(format_float(1000.0), "1.0e3");
+        assert_eq!(format_float(1.0e16), "1.0e16");
+        assert_eq!(format_float(0.1), "0.1");
+        assert_eq!(format_float(123.456), "123.456");
+        assert_eq!(format_float(1.0e-16), "1.0e-16");
+        assert_eq!(format_float(-2.5), "-2.5");
+    }
+
+    #[test]
+    fn format_float_zero() {
+        assert_eq!(format_float(0.0), "0.0");
+        assert_eq!(format_float(-0.0), "-0.0");
+    }
+
+    fn list(codes: &[i64]) -> Term {
+        Term::List(
+            codes.iter().map(|code| Term::Integer(*code)).collect(),
+            None,
+        )
+    }
+
+    #[test]
+    fn printable_list() {
+        assert_eq!(list(&[104, 105]).as_string().as_deref(), Some("hi"));
+        assert_eq!(list(&[104, 105]).to_string(), "\"hi\"");
+        assert_eq!(list(&[0x3bb, 9, 10]).to_string(), "\"\u{3bb}\\t\\n\"");
+    }
+
+    #[test]
+    fn non_printable_list() {
+        // a non-printable head, a codepoint out of range, the empty list and an improper list
+        assert_eq!(list(&[0, 104, 105]).as_string(), None);
+        assert_eq!(list(&[0, 104, 105]).to_string(), "[0, 104, 105]");
+        assert_eq!(list(&[0x110000]).as_string(), None);
+        assert_eq!(list(&[-1]).as_string(), None);
+        assert_eq!(list(&[]).as_string(), None);
+        assert_eq!(list(&[]).to_string(), "[]");
+        let improper = Term::List(vec![Term::Integer(104)], Some(Box::new(Term::Integer(105))));
+        assert_eq!(improper.as_string(), None);
+        let mixed = Term::List(vec![Term::Integer(104), Term::Atom("a".to_string())], None);
+        assert_eq!(mixed.as_string(), None);
+    }
+
+    #[test]
+    fn printable_binary() {
+        let binary = BinaryTerm::Heap("h\u{e9}llo\n".as_bytes().to_vec());
+        assert_eq!(binary.as_string(), Some("h\u{e9}llo\n"));
+        assert_eq!(binary.to_string(), "<<\"h\u{e9}llo\\n\">>");
+    }
+
+    #[test]
+    fn non_printable_binary() {
+        // not UTF-8, and UTF-8 with a control character
+        let binary = BinaryTerm::Heap(vec![0xff, 0xfe, 104]);
+        assert_eq!(binary.as_string(), None);
+        assert_eq!(binary.to_string(), "<<255, 254, 104>>");
+        let binary = BinaryTerm::Heap(vec![104, 1, 105]);
+        assert_eq!(binary.as_string(), None);
+        assert_eq!(binary.to_string(), "<<104, 1, 105>>");
+        assert_eq!(BinaryTerm::Heap(vec![]).to_string(), "<<>>");
+    }
+
+    #[test]
+    fn bitstring() {
+        // the whole bytes are printed as a string when they can be
+        let bits = BinaryTerm::Bits {
+            bytes: vec![b'h', b'i', 0b1010_0000],
+            bits: 3,
+        };
+        assert_eq!(bits.as_string(), None);
+        assert_eq!(bits.to_string(), "<<\"hi\", 5:3>>");
+        let bits = BinaryTerm::Bits {
+            bytes: vec![0b1000_0000],
+            bits: 1,
+        };
+        assert_eq!(bits.to_string(), "<<1:1>>");
+    }
+
+    #[test]
+    fn binary_preview() {
+        let binary = BinaryTerm::Heap(vec![0; 40]);
+        let preview = format!("<<{}, ... (40 bytes)>>", vec!["0"; 32].join(", "));
+        assert_eq!(binary.to_string(), preview);
+        // the first bytes of a ref-counted binary can end in the middle of a character
+        let binary = BinaryTerm::Refc {
+            address: 0,
+            offset: 0,
+            size: 10,
+            len: Some(10),
+            bytes: Some("ab\u{e9}".as_bytes()[..3].to_vec()),
+        };
+        assert_eq!(binary.as_string(), None);
+        assert_eq!(binary.to_string(), "<<\"ab\"... (10 bytes)>>");
+    }
+}
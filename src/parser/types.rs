@@ -30,7 +30,8 @@
 //      - If eager, we parse the section immediately and store the `Info`.
 //      - If lazy, we store the `Index` and defer parsing until later.
 //
-// The `parse_section` function defines how a particular section is parsed.
+// The `parse_section` function defines how a particular section is parsed, by dispatching to the
+// `FromGenericSection` implementation registered for its tag, see `register_section_parser`.
 //
 // The `from_index_map` function determines when a section is parsed.
 //
@@ -41,29 +42,34 @@
 //
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+use super::etf;
+use super::term::{BinaryTerm, FunTerm, Term};
+use super::walker::{self, DecodeLimits};
+use base64::prelude::*;
 use crossbeam::channel;
 use dashmap::DashMap;
-use ratatui::style::{Color, Style};
-use ratatui::text::{Line, Span, Text};
+use rayon::prelude::*;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
-use std::io::{self};
+use std::io::{self, BufRead, BufReader, Read};
 use std::os::unix::prelude::FileExt;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::OnceLock;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
 use std::thread::available_parallelism;
-use std::time::Instant;
 
 use std::thread; // Import rayon traits
 
-pub const MAX_DEPTH_PARSE_DATATYPE: usize = 5;
+// Bytes of a `=binary` section read to preview the binaries referring to it
+const BINARY_PREVIEW_LEN: usize = 256;
 
-static WORD_SIZE: OnceLock<u8> = OnceLock::new();
+// The length of a binary and its first bytes
+type BinaryHead = (usize, Vec<u8>);
 
 pub const TAG_PREAMBLE: &str = "erl_crash_dump";
 pub const TAG_ABORT: &str = "abort";
@@ -107,7 +113,7 @@ pub const TAG_VISIBLE_NODE: &str = "visible_node";
 pub const TAG_END: &str = "end";
 
 // Section tags - lifted from https://github.com/erlang/otp/blob/master/lib/observer/src/crashdump_viewer.erl#L121
-#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
 pub enum Tag {
     // Preamble of the crash dump.
     Preamble,
@@ -189,25 +195,39 @@ pub enum Tag {
     VisibleNode,
     // End of a section.
     End,
+    // A tag this version doesn't know about, e.g. from a newer OTP release or a corrupted line
+    // starting with `=`. These sections are indexed and shown raw.
+    Unknown(String),
 }
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub enum DumpSection {
     Preamble(Preamble),
     // Allocator(AllocatorInfo),
-    // Node(NodeInfo),
+    Node(NodeInfo),
     Proc(ProcInfo),
     // ProcHeap(ProcHeapInfo),
     ProcStack(ProcStackInfo),
     ProcMessages(ProcMessagesInfo),
-    // Scheduler(SchedulerInfo),
-    // Ets(EtsInfo),
+    ProcDictionary(ProcDictionaryInfo),
+    Scheduler(SchedulerInfo),
+    DirtyRunQueue(DirtyRunQueueInfo),
+    Allocator(AllocatorInfo),
+    LoadedModules(LoadedModules),
+    Mod(ModuleInfo),
+    Timer(TimerInfo),
+    IndexTable(IndexTableInfo),
+    HashTable(HashTableInfo),
+    Fun(FunInfo),
+    AllocatedAreas(Vec<AllocatedArea>),
+    Ets(EtsInfo),
     // Timer(TimerInfo),
-    // Port(PortInfo),
+    Port(PortInfo),
     Memory(MemoryInfo),
     // Atoms(Vec<String>),
-    // PersistentTerms(PersistentTermInfo),
+    PersistentTerms(PersistentTermInfo),
     // LoadedModules(LoadedModules),
     // Modules(ModuleInfo),
+    Custom(CustomSection),
     Generic(GenericSection),
 }
 
@@ -225,10 +245,52 @@ pub struct GenericSection {
     raw_lines: Vec<String>,        // For raw lines without key-value pairs
 }
 
-// TODO: once the format is stablized we can implement this trait
-// pub trait FromGenericSection: Sized {
-//     fn from_generic_section(section: &GenericSection) -> Result<Self, String>;
-// }
+impl GenericSection {
+    /// Tag of the section, without the leading `=`.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Identifier after the tag, e.g. the pid of a `=proc:<pid>` section.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Value of a `Key: value` line.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.data.get(key).map(String::as_str)
+    }
+
+    /// Every `Key: value` line of the section.
+    pub fn data(&self) -> &HashMap<String, String> {
+        &self.data
+    }
+
+    /// Lines that are not `Key: value` pairs, in dump order.
+    pub fn raw_lines(&self) -> &[String] {
+        &self.raw_lines
+    }
+}
+
+/// A typed section, decoded from the `GenericSection` of its tag.
+///
+/// Implementing this and registering `section_parser::<T>` for the tags `T` decodes is all a new
+/// section type needs, see `register_section_parser`.
+pub trait FromGenericSection: Sized {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError>;
+
+    /// Wraps the decoded section in its `DumpSection` variant.
+    fn into_dump_section(self) -> DumpSection;
+}
+
+/// A section decoded by a parser registered from outside this crate, see
+/// `register_section_parser`. The value is whatever the parser serialized.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct CustomSection {
+    pub tag: String,
+    pub id: Option<String>,
+    pub value: serde_json::Value,
+}
 
 impl FromStr for GenericSection {
     type Err = String;
@@ -250,8 +312,17 @@ impl FromStr for GenericSection {
 
         let mut raw_lines = Vec::new();
 
-        if tag == TAG_PROC_STACK {
+        if matches!(
+            tag.as_str(),
+            TAG_PROC_STACK
+                | TAG_PERSISTENT_TERMS
+                | TAG_VISIBLE_NODE
+                | TAG_HIDDEN_NODE
+                | TAG_NOT_CONNECTED
+        ) {
             // because the stack has repeating keys, in the y registers, we need to parse it different
+            // persistent terms are `key|value` lines, and atoms in them may contain `: `
+            // node sections have one `Remote link: ` line per link
             for line in lines {
                 raw_lines.push(line.to_string());
             }
@@ -322,63 +393,133 @@ pub fn string_tag_to_enum(tag: &str) -> Tag {
         t if t == TAG_TIMER => Tag::Timer,
         t if t == TAG_VISIBLE_NODE => Tag::VisibleNode,
         t if t == TAG_END => Tag::End,
-        _ => unreachable!(),
+        _ => Tag::Unknown(tag.to_string()),
     };
     tag_enum
 }
 
-fn parse_section(s: &str, _id: Option<&str>) -> Result<DumpSection, String> {
-    let section = GenericSection::from_str(s)?;
-    let id = section.id.clone().unwrap_or_else(|| "".to_string());
-    let raw_lines = &section.raw_lines;
-    let data = &section.data;
-
-    let section = match string_tag_to_enum(section.tag.as_str()) {
-        Tag::Preamble => {
-            // if the `System version` section has "[64-bit]", word size is 8 bytes, otherwise 4
-            let system_version = data
-                .get("System version")
-                .map(|s| s.clone())
-                .unwrap_or_else(|| "".to_string());
-            let word_size_local = if system_version.contains("[64-bit]") {
-                8
-            } else {
-                4
-            };
-
-            // Set the global WORD_SIZE
-            WORD_SIZE
-                .set(word_size_local)
-                .expect("WORD_SIZE should only be set once");
-
-            let preamble = Preamble {
-                version: id,
-                time: raw_lines[0].clone(),
-                slogan: data["Slogan"].clone(),
-                erts: system_version.clone(), // Now system_version owns the String
-                taints: data["Taints"].clone(),
-                atom_count: data["Atoms"].parse::<i64>().unwrap(),
-                calling_thread: data["Calling Thread"].clone(),
-                word_size: word_size_local,
-            };
-            DumpSection::Preamble(preamble)
+// Keys every section with this tag has. A section cut short lacks the last ones, so it is
+// counted as incomplete, while the fields it has are still shown.
+fn required_keys(tag: &Tag) -> &'static [&'static str] {
+    match tag {
+        // `Calling Thread` is only written by newer emulators
+        Tag::Preamble => &["Slogan", "System version", "Atoms"],
+        Tag::Memory => &[
+            "total",
+            "processes",
+            "processes_used",
+            "system",
+            "atom",
+            "atom_used",
+            "binary",
+            "code",
+            "ets",
+        ],
+        Tag::Proc => &["State", "Reductions", "Stack+heap", "Memory"],
+        Tag::Port => &["State", "Slot", "Connected"],
+        Tag::Ets => &["Table", "Name", "Objects", "Words"],
+        Tag::Scheduler | Tag::DirtyCpuScheduler | Tag::DirtyIoScheduler => {
+            &["Scheduler Sleep Info Flags", "Current Process"]
         }
+        Tag::Timer => &["Message", "Time left"],
+        Tag::Mod => &["Current size"],
+        Tag::Fun => &["Module", "Uniq", "Index", "Address", "Refc"],
+        Tag::IndexTable => &["size", "limit", "entries"],
+        Tag::HashTable => &["size", "used", "objs", "depth"],
+        Tag::LoadedModules => &["Current code", "Old code"],
+        _ => &[],
+    }
+}
 
-        Tag::Memory => DumpSection::Memory(MemoryInfo::from_generic_section(&data)),
+/// Returns true if `contents`, a whole section, lacks some of the keys sections with this tag
+/// always have, which is what a section cut short by a truncated dump looks like.
+pub fn is_section_incomplete(tag: &Tag, contents: &str) -> bool {
+    required_keys(tag).iter().any(|key| {
+        !contents.lines().skip(1).any(|line| {
+            line.strip_prefix(key)
+                .is_some_and(|rest| rest.starts_with(':'))
+        })
+    })
+}
 
-        Tag::Proc => DumpSection::Proc(ProcInfo::from_generic_section(&section)),
+/// Returns true if several sections with this tag can share the same id.
+///
+/// For example, a process that owns several ETS tables has one `=ets:<pid>` section per table.
+/// These sections are indexed as a list instead of a map keyed by id.
+pub fn tag_has_repeated_ids(tag: &Tag) -> bool {
+    matches!(
+        tag,
+        Tag::Ets | Tag::Timer | Tag::HashTable | Tag::Unknown(_)
+    )
+}
 
-        Tag::ProcStack => {
-            DumpSection::ProcStack(ProcStackInfo::from_generic_section(&section).unwrap())
-        }
+/// Decodes a section into a `DumpSection`.
+pub type SectionParser = fn(&GenericSection) -> Result<DumpSection, SectionError>;
 
-        Tag::ProcMessages => {
-            DumpSection::ProcMessages(ProcMessagesInfo::from_generic_section(&section).unwrap())
-        }
+/// The `SectionParser` of a `FromGenericSection` type.
+pub fn section_parser<T: FromGenericSection>(
+    section: &GenericSection,
+) -> Result<DumpSection, SectionError> {
+    T::from_generic_section(section).map(T::into_dump_section)
+}
 
-        _ => DumpSection::Generic(section),
-    };
-    Ok(section)
+static SECTION_PARSERS: OnceLock<RwLock<HashMap<Tag, SectionParser>>> = OnceLock::new();
+
+fn section_parsers() -> &'static RwLock<HashMap<Tag, SectionParser>> {
+    SECTION_PARSERS.get_or_init(|| {
+        let parsers: [(Tag, SectionParser); 25] = [
+            (Tag::Preamble, section_parser::<Preamble>),
+            (Tag::Memory, section_parser::<MemoryInfo>),
+            (Tag::Proc, section_parser::<ProcInfo>),
+            (Tag::ProcStack, section_parser::<ProcStackInfo>),
+            (Tag::ProcMessages, section_parser::<ProcMessagesInfo>),
+            (Tag::ProcDictionary, section_parser::<ProcDictionaryInfo>),
+            (Tag::Ets, section_parser::<EtsInfo>),
+            (Tag::Port, section_parser::<PortInfo>),
+            (Tag::Scheduler, section_parser::<SchedulerInfo>),
+            (Tag::DirtyCpuScheduler, section_parser::<SchedulerInfo>),
+            (Tag::DirtyIoScheduler, section_parser::<SchedulerInfo>),
+            (Tag::Allocator, section_parser::<AllocatorInfo>),
+            (Tag::LoadedModules, section_parser::<LoadedModules>),
+            (Tag::Mod, section_parser::<ModuleInfo>),
+            (Tag::Timer, section_parser::<TimerInfo>),
+            (Tag::PersistentTerms, section_parser::<PersistentTermInfo>),
+            (Tag::IndexTable, section_parser::<IndexTableInfo>),
+            (Tag::HashTable, section_parser::<HashTableInfo>),
+            (Tag::Fun, section_parser::<FunInfo>),
+            (Tag::AllocatedAreas, section_parser::<Vec<AllocatedArea>>),
+            (Tag::VisibleNode, section_parser::<NodeInfo>),
+            (Tag::HiddenNode, section_parser::<NodeInfo>),
+            (Tag::NotConnected, section_parser::<NodeInfo>),
+            (Tag::DirtyCpuRunQueue, section_parser::<DirtyRunQueueInfo>),
+            (Tag::DirtyIoRunQueue, section_parser::<DirtyRunQueueInfo>),
+        ];
+        RwLock::new(HashMap::from(parsers))
+    })
+}
+
+/// Registers the parser of the sections with this tag, returning the one it replaces.
+///
+/// This has to happen before `CDParser::parse`. Sections with a tag the `CrashDump` has no
+/// field for, e.g. a `Tag::Unknown` from a newer OTP release, are loaded when they have a parser
+/// and kept in `CrashDump::custom_sections` if it returns a `DumpSection::Custom`.
+pub fn register_section_parser(tag: Tag, parser: SectionParser) -> Option<SectionParser> {
+    section_parsers().write().unwrap().insert(tag, parser)
+}
+
+/// Returns true if sections with this tag are decoded, rather than kept as a `GenericSection`.
+pub fn has_section_parser(tag: &Tag) -> bool {
+    section_parsers().read().unwrap().contains_key(tag)
+}
+
+fn parse_section(s: &str, _id: Option<&str>) -> Result<DumpSection, SectionError> {
+    let section = GenericSection::from_str(s).map_err(SectionError::Malformed)?;
+    let tag = string_tag_to_enum(section.tag.as_str());
+    let parser = section_parsers().read().unwrap().get(&tag).copied();
+    match parser {
+        Some(parser) => parser(&section),
+        None => Ok(DumpSection::Generic(section)),
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)] // Added PartialEq for comparison in tests if needed
@@ -389,6 +530,110 @@ pub struct IndexRow {
     pub length: String,
 }
 
+/// Why a section could not be loaded or decoded.
+#[derive(Debug)]
+pub enum SectionError {
+    /// Reading the section from the dump failed
+    Read(io::Error),
+    /// The section doesn't have the layout its tag calls for
+    Malformed(String),
+    /// A term in the section doesn't follow the heap encoding
+    InvalidTerm(String),
+}
+
+impl fmt::Display for SectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SectionError::Read(err) => write!(f, "read failed: {}", err),
+            SectionError::Malformed(reason) => write!(f, "malformed section: {}", reason),
+            SectionError::InvalidTerm(reason) => write!(f, "invalid term: {}", reason),
+        }
+    }
+}
+
+// the term parsers build their messages with `format!`
+impl From<String> for SectionError {
+    fn from(reason: String) -> Self {
+        SectionError::InvalidTerm(reason)
+    }
+}
+
+/// An error found while parsing a crash dump.
+///
+/// Errors in a section carry where the section is, so that it can be opened in the raw section
+/// browser and quoted in a bug report.
+#[derive(Debug)]
+pub enum ParseError {
+    /// The dump could not be opened or indexed
+    Io(io::Error),
+    /// A section could not be loaded or decoded
+    Section {
+        tag: Tag,
+        id: Option<String>,
+        /// Byte offset of the section header in the dump
+        offset: u64,
+        cause: SectionError,
+    },
+}
+
+impl ParseError {
+    pub fn section(tag: Tag, index_row: &IndexRow, cause: SectionError) -> Self {
+        ParseError::Section {
+            tag,
+            id: index_row.id.clone(),
+            offset: index_row.start.parse().unwrap_or(0),
+            cause,
+        }
+    }
+
+    /// Byte offset of the section the error is in, if it is in one.
+    pub fn offset(&self) -> Option<u64> {
+        match self {
+            ParseError::Io(_) => None,
+            ParseError::Section { offset, .. } => Some(*offset),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io(err) => write!(f, "{}", err),
+            ParseError::Section {
+                tag,
+                id: Some(id),
+                offset,
+                cause,
+            } => write!(f, "{:?} section {} at byte {}: {}", tag, id, offset, cause),
+            ParseError::Section {
+                tag,
+                id: None,
+                offset,
+                cause,
+            } => write!(f, "{:?} section at byte {}: {}", tag, offset, cause),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io(err)
+            | ParseError::Section {
+                cause: SectionError::Read(err),
+                ..
+            } => Some(err),
+            ParseError::Section { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(err: io::Error) -> Self {
+        ParseError::Io(err)
+    }
+}
+
 // pub type IndexMap = HashMap<Tag, HashMap<Option<String>, IndexRow>>;
 #[derive(Debug, Clone)]
 pub enum IndexValue {
@@ -434,29 +679,51 @@ pub struct CrashDump {
     pub preamble: Preamble,
     pub memory: MemoryInfo,
     pub allocators: Vec<InfoOrIndex<AllocatorInfo>>,
+    pub distribution: DistributionInfo,
     pub nodes: Vec<InfoOrIndex<NodeInfo>>,
 
     pub processes: DashMap<String, InfoOrIndex<ProcInfo>>,
     pub processes_heap: DashMap<String, InfoOrIndex<ProcHeapInfo>>,
     pub processes_stack: DashMap<String, InfoOrIndex<ProcStackInfo>>,
     pub processes_messages: DashMap<String, InfoOrIndex<ProcMessagesInfo>>,
+    pub processes_dictionary: DashMap<String, InfoOrIndex<ProcDictionaryInfo>>,
     pub ports: DashMap<String, InfoOrIndex<PortInfo>>,
     pub schedulers: Vec<InfoOrIndex<SchedulerInfo>>,
+    pub dirty_run_queues: Vec<InfoOrIndex<DirtyRunQueueInfo>>,
     pub ets: Vec<InfoOrIndex<EtsInfo>>,
     pub timers: Vec<InfoOrIndex<TimerInfo>>,
     pub atoms: Vec<InfoOrIndex<String>>,
-    pub loaded_modules: Vec<InfoOrIndex<LoadedModules>>,
+    pub index_tables: Vec<IndexTableInfo>,
+    pub hash_tables: Vec<HashTableInfo>,
+    pub funs: Vec<FunInfo>,
+    pub allocated_areas: Vec<AllocatedArea>,
+    pub loaded_modules: LoadedModules,
     pub persistent_terms: Vec<InfoOrIndex<PersistentTermInfo>>,
+    /// Sections decoded by parsers registered with `register_section_parser`, in dump order.
+    pub custom_sections: Vec<CustomSection>,
     /// Raw sections that have not yet been parsed.
     ///
     /// This is used to store sections that we don't know how to parse yet, or sections that
     /// we want to defer parsing until later.
     pub raw_sections: DashMap<String, Vec<u8>>,
+    /// Problems found while loading the dump that didn't stop it, e.g. unknown section tags.
+    pub warnings: Vec<String>,
+    /// Set when the dump has no `=end` section, e.g. because writing it timed out.
+    pub truncation: TruncationInfo,
+    /// Sections that failed to load or decode, skipped while loading the rest.
+    pub errors: Vec<ParseError>,
     pub group_info_map: HashMap<String, GroupInfo>,
+    /// The dump, for the sections read on demand while decoding terms.
+    pub file_path: PathBuf,
+    /// Budget for decoding each term in the heap encoding, beyond which it is left truncated.
+    pub decode_limits: DecodeLimits,
 
     // derived data
     pub all_heap_addresses: DashMap<String, String>,
-    pub visited_binaries: DashMap<String, usize>,
+    // `=binary` sections by address, read when a term refers to them
+    pub binaries: DashMap<String, IndexRow>,
+    // Length and first bytes of the binaries read so far, by address
+    pub binary_previews: DashMap<String, BinaryHead>,
 
     // unused
     pub all_visited_heap_addresses: HashSet<String>,
@@ -488,23 +755,37 @@ impl CrashDump {
                 ets: 0,
             },
             allocators: vec![],
+            distribution: DistributionInfo::default(),
             nodes: vec![],
             processes: DashMap::new(),
             processes_heap: DashMap::new(),
             processes_stack: DashMap::new(),
             processes_messages: DashMap::new(),
+            processes_dictionary: DashMap::new(),
             ports: DashMap::new(),
             schedulers: vec![],
+            dirty_run_queues: vec![],
             ets: vec![],
             timers: vec![],
             atoms: vec![],
-            loaded_modules: vec![],
+            index_tables: vec![],
+            hash_tables: vec![],
+            funs: vec![],
+            allocated_areas: vec![],
+            loaded_modules: LoadedModules::default(),
             persistent_terms: vec![],
+            custom_sections: vec![],
             raw_sections: DashMap::new(),
+            warnings: vec![],
+            truncation: TruncationInfo::default(),
+            errors: vec![],
             group_info_map: HashMap::new(),
+            file_path: PathBuf::new(),
+            decode_limits: DecodeLimits::default(),
             all_heap_addresses: DashMap::new(),
             all_visited_heap_addresses: HashSet::new(),
-            visited_binaries: DashMap::new(),
+            binaries: DashMap::new(),
+            binary_previews: DashMap::new(),
 
             visited_binaries_found: DashMap::new(),
 
@@ -529,6 +810,161 @@ impl CrashDump {
         Ok(contents.to_string())
     }
 
+    /// Reads at most `max_len` bytes of a section, for showing it raw.
+    pub fn load_section_head(
+        index_row: &IndexRow,
+        file: &File,
+        max_len: usize,
+    ) -> io::Result<String> {
+        let start_offset: u64 = index_row.start.parse().unwrap_or(0);
+        let length: u64 = index_row.length.parse().unwrap_or(0);
+
+        let mut buffer = vec![0; length.min(max_len as u64) as usize];
+        file.read_exact_at(&mut buffer, start_offset)?;
+
+        Ok(String::from_utf8_lossy(&buffer).to_string())
+    }
+
+    /// Allocators aggregated by type, see [`AllocatorSummary::from_allocators`].
+    pub fn allocator_summaries(&self) -> Vec<AllocatorSummary> {
+        AllocatorSummary::from_allocators(self.allocators.iter().filter_map(|allocator| {
+            match allocator {
+                InfoOrIndex::Info(info) => Some(info),
+                InfoOrIndex::Index(_) => None,
+            }
+        }))
+    }
+
+    /// Maps each module to the processes running code in it, either through their program
+    /// counter or through a frame on their stack. This loads every `=proc_stack` section, so
+    /// callers should cache the result. The stack sections that fail to load or parse are
+    /// returned along with the users found in the others.
+    pub fn module_users(&self, file: &File) -> (HashMap<String, Vec<String>>, Vec<ParseError>) {
+        let mut users: HashMap<String, HashSet<String>> = HashMap::new();
+
+        for proc_ref in self.processes.iter() {
+            if let InfoOrIndex::Info(proc_info) = proc_ref.value() {
+                let module = &proc_info.program_counter.module;
+                if !module.is_empty() {
+                    users
+                        .entry(module.clone())
+                        .or_default()
+                        .insert(proc_info.pid.clone());
+                }
+            }
+        }
+
+        let stack_rows: Vec<(String, IndexRow)> = self
+            .processes_stack
+            .iter()
+            .filter_map(|stack_ref| match stack_ref.value() {
+                InfoOrIndex::Index(index_row) => Some((stack_ref.key().clone(), index_row.clone())),
+                InfoOrIndex::Info(_) => None,
+            })
+            .collect();
+        let stack_modules: Vec<Result<(String, HashSet<String>), ParseError>> = stack_rows
+            .par_iter()
+            .map(|(pid, index_row)| {
+                let contents = Self::load_located_section(&Tag::ProcStack, index_row, file)?;
+                let modules =
+                    match Self::parse_located_section(&Tag::ProcStack, index_row, &contents)? {
+                        DumpSection::ProcStack(proc_stack) => proc_stack
+                            .frames
+                            .into_iter()
+                            .map(|frame| frame.module)
+                            .filter(|module| !module.is_empty())
+                            .collect(),
+                        _ => HashSet::new(),
+                    };
+                Ok((pid.clone(), modules))
+            })
+            .collect();
+        let mut errors = Vec::new();
+        for result in stack_modules {
+            match result {
+                Ok((pid, modules)) => {
+                    for module in modules {
+                        users.entry(module).or_default().insert(pid.clone());
+                    }
+                }
+                Err(err) => errors.push(err),
+            }
+        }
+
+        let users = users
+            .into_iter()
+            .map(|(module, pids)| {
+                let mut pids: Vec<String> = pids.into_iter().collect();
+                pids.sort();
+                (module, pids)
+            })
+            .collect();
+        (users, errors)
+    }
+
+    /// Streams the atoms of the `=atoms` sections line by line, without loading whole sections
+    /// into memory. Atoms are written the way the dump prints them, quoted when needed, and the
+    /// most recently created atom comes first.
+    pub fn stream_atoms<'a>(
+        &'a self,
+        file: &'a File,
+    ) -> impl Iterator<Item = io::Result<String>> + 'a {
+        self.atoms.iter().flat_map(move |atoms| {
+            let lines: Box<dyn Iterator<Item = io::Result<String>>> = match atoms {
+                InfoOrIndex::Index(index_row) => {
+                    let start: u64 = index_row.start.parse().unwrap_or(0);
+                    let length: u64 = index_row.length.parse().unwrap_or(0);
+                    let reader = SectionReader {
+                        file,
+                        offset: start,
+                        end: start + length,
+                    };
+                    // skip the `=atoms` header
+                    Box::new(BufReader::new(reader).lines().skip(1))
+                }
+                InfoOrIndex::Info(atom) => Box::new(std::iter::once(Ok(atom.clone()))),
+            };
+            lines
+        })
+    }
+
+    /// The `=index_table` section named `name`, e.g. `atom_tab`.
+    pub fn index_table(&self, name: &str) -> Option<&IndexTableInfo> {
+        self.index_tables.iter().find(|table| table.name == name)
+    }
+
+    /// Returns the pending timers of `pid`, the ones firing first first.
+    pub fn timers_for_process(&self, pid: &str) -> Vec<&TimerInfo> {
+        let mut timers: Vec<&TimerInfo> = self
+            .timers
+            .iter()
+            .filter_map(|timer| match timer {
+                InfoOrIndex::Info(timer_info) if timer_info.pid == pid => Some(timer_info),
+                _ => None,
+            })
+            .collect();
+        timers.sort_by_key(|timer| timer.time_left);
+        timers
+    }
+
+    /// Returns the ports connected or linked to `pid`, sorted by port id.
+    pub fn ports_for_process(&self, pid: &str) -> Vec<PortInfo> {
+        let mut ports: Vec<PortInfo> = self
+            .ports
+            .iter()
+            .filter_map(|port_ref| match port_ref.value() {
+                InfoOrIndex::Info(port)
+                    if port.connected == pid || port.links.iter().any(|l| l == pid) =>
+                {
+                    Some(port.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        ports.sort_by_cached_key(|port| id_sort_key(&port.id));
+        ports
+    }
+
     /// Creates a new `CrashDump` from an `IndexMap`.
     ///
     /// This function controls whether the parsing is eager or lazy.
@@ -536,10 +972,9 @@ impl CrashDump {
     /// If eager, we parse the section immediately and store the `Info`.
     ///
     /// If lazy, we store the `Index` and defer parsing until later.
-    pub fn from_index_map(index_map: &IndexMap, file_path: &PathBuf) -> io::Result<Self> {
-        let now = Instant::now();
-
+    pub fn from_index_map(index_map: &IndexMap, file_path: &PathBuf) -> Result<Self, ParseError> {
         let crash_dump = Arc::new(Mutex::new(CrashDump::new()));
+        crash_dump.lock().unwrap().file_path = file_path.clone();
         let file = File::open(file_path)?;
         let file = Arc::new(file);
 
@@ -548,91 +983,131 @@ impl CrashDump {
             channel::Receiver<(Tag, String, IndexRow)>,
         ) = channel::unbounded();
 
+        // a dump cut short has no `=end`, and the section it stops in is incomplete
+        let has_end = index_map.contains_key(&Tag::End);
+        let last_start = if has_end {
+            None
+        } else {
+            // an `=abort` section is written after the section the dump stopped in
+            index_map
+                .iter()
+                .filter(|(tag, _)| **tag != Tag::Abort)
+                .flat_map(|(_, index_value)| match index_value {
+                    IndexValue::Map(map) => map.values().collect::<Vec<_>>(),
+                    IndexValue::List(list) => list.iter().collect(),
+                })
+                .filter_map(|index_row| index_row.start.parse::<u64>().ok())
+                .max()
+        };
+        let abort_reason = index_map
+            .get(&Tag::Abort)
+            .and_then(|index_value| match index_value {
+                IndexValue::Map(map) => map.keys().next().cloned(),
+                IndexValue::List(list) => {
+                    list.first().map(|row| row.id.clone().unwrap_or_default())
+                }
+            });
+        crash_dump.lock().unwrap().truncation = TruncationInfo {
+            truncated: !has_end || abort_reason.is_some(),
+            abort_reason,
+            incomplete_sections: 0,
+        };
+        let incomplete_sections = Arc::new(AtomicUsize::new(last_start.map_or(0, |_| 1)));
+
         let num_consumers = available_parallelism().unwrap().get();
 
-        println!("num_consumers: {}", num_consumers);
         let mut handles = Vec::new();
 
         for _ in 0..num_consumers {
             let rx = rx.clone();
             let file = Arc::clone(&file);
             let crash_dump = Arc::clone(&crash_dump);
+            let incomplete_sections = Arc::clone(&incomplete_sections);
             let handle = thread::spawn(move || {
                 while let Ok((tag, id, index_row)) = rx.recv() {
-                    let result = Self::load_section(&index_row, &file);
-                    match tag {
-                        Tag::Proc => {
-                            if let Ok(contents) = result {
-                                if let Ok(DumpSection::Proc(proc)) =
-                                    parse_section(&contents, Some(&id))
-                                {
-                                    let cd = crash_dump.lock().unwrap();
-                                    cd.processes.insert(id, InfoOrIndex::Info(proc));
-                                }
-                            }
+                    let section = match Self::load_parsed_section(
+                        &tag,
+                        &index_row,
+                        &file,
+                        last_start,
+                        &incomplete_sections,
+                    ) {
+                        Ok(section) => section,
+                        Err(err) => {
+                            crash_dump.lock().unwrap().errors.push(err);
+                            continue;
                         }
-                        Tag::ProcHeap => {
-                            if let Ok(contents) = result {
-                                if let Ok(DumpSection::Generic(proc_heap)) =
-                                    parse_section(&contents, Some(&id))
-                                {
-                                    let cd = crash_dump.lock().unwrap();
-                                    proc_heap.raw_lines.into_iter().for_each(|line| {
-                                        let parts: Vec<&str> = line.splitn(2, ':').collect();
-                                        if parts.len() == 2 {
-                                            cd.all_heap_addresses
-                                                .insert(parts[0].to_string(), parts[1].to_string());
-                                        } else {
-                                            eprintln!(
-                                                "Line does not contain expected delimiter: {}",
-                                                line
-                                            );
-                                        }
-                                    });
-                                }
-                            }
+                    };
+                    match section {
+                        DumpSection::Proc(proc) => {
+                            let cd = crash_dump.lock().unwrap();
+                            cd.processes.insert(id, InfoOrIndex::Info(proc));
                         }
-                        Tag::Literals => {
-                            if let Ok(contents) = result {
-                                if let Ok(DumpSection::Generic(literals)) =
-                                    parse_section(&contents, None)
-                                {
-                                    let cd = crash_dump.lock().unwrap();
-                                    literals.raw_lines.into_iter().for_each(|line| {
-                                        let parts: Vec<&str> = line.splitn(2, ':').collect();
-                                        if parts.len() == 2 {
-                                            cd.all_heap_addresses
-                                                .insert(parts[0].to_string(), parts[1].to_string());
-                                        } else {
-                                            eprintln!(
-                                                "Line does not contain expected delimiter: {}",
-                                                line
-                                            );
-                                        }
-                                    });
+                        // the lines of `=proc_heap` and `=literals` sections
+                        DumpSection::Generic(heap) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            for line in heap.raw_lines {
+                                match line.split_once(':') {
+                                    Some((addr, term)) => {
+                                        cd.all_heap_addresses
+                                            .insert(addr.to_string(), term.to_string());
+                                    }
+                                    None => cd.errors.push(ParseError::section(
+                                        tag.clone(),
+                                        &index_row,
+                                        SectionError::Malformed(format!(
+                                            "Line does not contain expected delimiter: {}",
+                                            line
+                                        )),
+                                    )),
                                 }
                             }
                         }
-                        Tag::PersistentTerms => {
-                            if let Ok(contents) = result {
-                                if let Ok(DumpSection::Generic(persistent_terms)) =
-                                    parse_section(&contents, None)
-                                {
-                                    let cd = crash_dump.lock().unwrap();
-                                    persistent_terms.raw_lines.into_iter().for_each(|line| {
-                                        let parts: Vec<&str> = line.splitn(2, '|').collect();
-                                        if parts.len() == 2 {
-                                            cd.all_heap_addresses
-                                                .insert(parts[0].to_string(), parts[1].to_string());
-                                        } else {
-                                            eprintln!(
-                                                "Line does not contain expected delimiter: {}",
-                                                line
-                                            );
-                                        }
-                                    });
-                                }
+                        DumpSection::Ets(ets) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.ets.push(InfoOrIndex::Info(ets));
+                        }
+                        DumpSection::Port(port) => {
+                            let cd = crash_dump.lock().unwrap();
+                            cd.ports.insert(id, InfoOrIndex::Info(port));
+                        }
+                        DumpSection::Scheduler(scheduler) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.schedulers.push(InfoOrIndex::Info(scheduler));
+                        }
+                        DumpSection::Timer(timer) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.timers.push(InfoOrIndex::Info(timer));
+                        }
+                        DumpSection::Mod(module) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.loaded_modules.modules.push(module);
+                        }
+                        DumpSection::Allocator(allocator) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.allocators.push(InfoOrIndex::Info(allocator));
+                        }
+                        DumpSection::Fun(fun) => {
+                            let mut cd = crash_dump.lock().unwrap();
+                            cd.funs.push(fun);
+                        }
+                        DumpSection::PersistentTerms(mut persistent_terms) => {
+                            // decoded once all heap addresses are loaded, see
+                            // `decode_deferred_terms`
+                            let mut cd = crash_dump.lock().unwrap();
+                            for line in std::mem::take(&mut persistent_terms.malformed_lines) {
+                                cd.errors.push(ParseError::section(
+                                    tag.clone(),
+                                    &index_row,
+                                    SectionError::Malformed(format!(
+                                        "Line does not contain expected delimiter: {}",
+                                        line
+                                    )),
+                                ));
                             }
+                            persistent_terms.index_row = Some(index_row.clone());
+                            cd.persistent_terms
+                                .push(InfoOrIndex::Info(persistent_terms));
                         }
                         _ => {}
                     }
@@ -641,21 +1116,52 @@ impl CrashDump {
             handles.push(handle);
         }
 
+        let mut warnings: Vec<String> = index_map
+            .iter()
+            .filter_map(|(tag, index_value)| match tag {
+                Tag::Unknown(name) => Some(format!(
+                    "Unknown section tag ={} ({} sections), shown in the raw section browser",
+                    name,
+                    index_value.len()
+                )),
+                _ => None,
+            })
+            .collect();
+        warnings.sort();
+        crash_dump.lock().unwrap().warnings = warnings;
+
+        crash_dump.lock().unwrap().distribution.distributed =
+            index_map.contains_key(&Tag::Node) && !index_map.contains_key(&Tag::NoDistribution);
+
+        // a section that fails to load is recorded and skipped, the rest of the dump is still
+        // worth looking at
+        let load_parsed_section = |tag: &Tag, index_row: &IndexRow| -> Option<DumpSection> {
+            Self::load_parsed_section(tag, index_row, &file, last_start, &incomplete_sections)
+                .map_err(|err| crash_dump.lock().unwrap().errors.push(err))
+                .ok()
+        };
+        // sections with a tag of their own, decoded by a parser registered outside this crate
+        let load_custom_section = |tag: &Tag, index_row: &IndexRow| {
+            if let Some(DumpSection::Custom(section)) = load_parsed_section(tag, index_row) {
+                crash_dump.lock().unwrap().custom_sections.push(section);
+            }
+        };
+
         for (tag, index_value) in index_map {
             match index_value {
                 IndexValue::Map(inner_map) => {
                     for (id, index_row) in inner_map {
                         match tag {
                             Tag::Preamble => {
-                                let contents = Self::load_section(&index_row, &file)?;
-                                if let Ok(DumpSection::Preamble(preamble)) =
-                                    parse_section(&contents, Some(&id))
+                                if let Some(DumpSection::Preamble(preamble)) =
+                                    load_parsed_section(tag, index_row)
                                 {
                                     crash_dump.lock().unwrap().preamble = preamble;
                                 }
                             }
                             Tag::Proc => {
-                                tx.send((*tag, id.clone(), index_row.clone())).unwrap();
+                                tx.send((tag.clone(), id.clone(), index_row.clone()))
+                                    .unwrap();
                             }
                             Tag::ProcHeap => {
                                 crash_dump
@@ -663,7 +1169,8 @@ impl CrashDump {
                                     .unwrap()
                                     .processes_heap
                                     .insert(id.clone(), InfoOrIndex::Index(index_row.clone()));
-                                tx.send((*tag, id.clone(), index_row.clone())).unwrap();
+                                tx.send((tag.clone(), id.clone(), index_row.clone()))
+                                    .unwrap();
                             }
                             Tag::ProcStack => {
                                 crash_dump
@@ -679,22 +1186,52 @@ impl CrashDump {
                                     .processes_messages
                                     .insert(id.clone(), InfoOrIndex::Index(index_row.clone()));
                             }
+                            Tag::ProcDictionary => {
+                                crash_dump
+                                    .lock()
+                                    .unwrap()
+                                    .processes_dictionary
+                                    .insert(id.clone(), InfoOrIndex::Index(index_row.clone()));
+                            }
                             Tag::Binary => {
-                                if let Some(binary_id) = &index_row.id {
-                                    let len = index_row.length.parse::<usize>().unwrap_or(0);
+                                crash_dump
+                                    .lock()
+                                    .unwrap()
+                                    .binaries
+                                    .insert(id.clone(), index_row.clone());
+                            }
+                            Tag::Port
+                            | Tag::Mod
+                            | Tag::Allocator
+                            | Tag::Scheduler
+                            | Tag::DirtyCpuScheduler
+                            | Tag::DirtyIoScheduler => {
+                                tx.send((tag.clone(), id.clone(), index_row.clone()))
+                                    .unwrap();
+                            }
+                            Tag::Node => {
+                                crash_dump.lock().unwrap().distribution.node_name =
+                                    unquote_atom(id).to_string();
+                            }
+                            Tag::VisibleNode | Tag::HiddenNode | Tag::NotConnected => {
+                                if let Some(DumpSection::Node(node)) =
+                                    load_parsed_section(tag, index_row)
+                                {
                                     crash_dump
                                         .lock()
                                         .unwrap()
-                                        .visited_binaries
-                                        .insert(binary_id.clone(), len);
+                                        .nodes
+                                        .push(InfoOrIndex::Info(node));
                                 }
                             }
-                            Tag::Literals => {
-                                tx.send((*tag, id.clone(), index_row.clone())).unwrap();
-                            }
-                            Tag::PersistentTerms => {
-                                tx.send((*tag, id.clone(), index_row.clone())).unwrap();
+                            Tag::IndexTable => {
+                                if let Some(DumpSection::IndexTable(index_table)) =
+                                    load_parsed_section(tag, index_row)
+                                {
+                                    crash_dump.lock().unwrap().index_tables.push(index_table);
+                                }
                             }
+                            _ if has_section_parser(tag) => load_custom_section(tag, index_row),
                             _ => {}
                         }
                     }
@@ -703,13 +1240,65 @@ impl CrashDump {
                     for index_row in index_rows {
                         match tag {
                             Tag::Memory => {
-                                let contents = Self::load_section(&index_row, &file)?;
-                                if let Ok(DumpSection::Memory(memory)) =
-                                    parse_section(&contents, None)
+                                if let Some(DumpSection::Memory(memory)) =
+                                    load_parsed_section(tag, index_row)
                                 {
                                     crash_dump.lock().unwrap().memory = memory;
                                 }
                             }
+                            Tag::Ets
+                            | Tag::Timer
+                            | Tag::Literals
+                            | Tag::PersistentTerms
+                            | Tag::Fun => {
+                                let id = index_row.id.clone().unwrap_or_default();
+                                tx.send((tag.clone(), id, index_row.clone())).unwrap();
+                            }
+                            Tag::HashTable => {
+                                if let Some(DumpSection::HashTable(hash_table)) =
+                                    load_parsed_section(tag, index_row)
+                                {
+                                    crash_dump.lock().unwrap().hash_tables.push(hash_table);
+                                }
+                            }
+                            Tag::AllocatedAreas => {
+                                if let Some(DumpSection::AllocatedAreas(allocated_areas)) =
+                                    load_parsed_section(tag, index_row)
+                                {
+                                    crash_dump.lock().unwrap().allocated_areas = allocated_areas;
+                                }
+                            }
+                            Tag::Atoms => {
+                                // atoms can run into the millions, so they are only streamed
+                                // from the file when needed, see `CrashDump::stream_atoms`
+                                crash_dump
+                                    .lock()
+                                    .unwrap()
+                                    .atoms
+                                    .push(InfoOrIndex::Index(index_row.clone()));
+                            }
+                            Tag::LoadedModules => {
+                                if let Some(DumpSection::LoadedModules(loaded_modules)) =
+                                    load_parsed_section(tag, index_row)
+                                {
+                                    let mut cd = crash_dump.lock().unwrap();
+                                    // the =mod sections may have been parsed already
+                                    cd.loaded_modules.current_code = loaded_modules.current_code;
+                                    cd.loaded_modules.old_code = loaded_modules.old_code;
+                                }
+                            }
+                            Tag::DirtyCpuRunQueue | Tag::DirtyIoRunQueue => {
+                                if let Some(DumpSection::DirtyRunQueue(run_queue)) =
+                                    load_parsed_section(tag, index_row)
+                                {
+                                    crash_dump
+                                        .lock()
+                                        .unwrap()
+                                        .dirty_run_queues
+                                        .push(InfoOrIndex::Info(run_queue));
+                                }
+                            }
+                            _ if has_section_parser(tag) => load_custom_section(tag, index_row),
                             _ => {}
                         }
                     }
@@ -722,8 +1311,11 @@ impl CrashDump {
             handle.join().unwrap();
         }
 
-        let elapsed = now.elapsed();
-        println!("Parsing everything took: {:.2?}", elapsed);
+        // decoding can follow heap addresses, so wait until the workers have loaded them all
+        crash_dump.lock().unwrap().decode_deferred_terms();
+
+        crash_dump.lock().unwrap().truncation.incomplete_sections =
+            incomplete_sections.load(Ordering::Relaxed);
 
         //println!("handle {:?}", handles);
         Ok(Arc::try_unwrap(crash_dump)
@@ -732,350 +1324,444 @@ impl CrashDump {
             .unwrap())
     }
 
+    // Loads and parses one section, locating the error if it fails.
+    fn load_parsed_section(
+        tag: &Tag,
+        index_row: &IndexRow,
+        file: &File,
+        last_start: Option<u64>,
+        incomplete_sections: &AtomicUsize,
+    ) -> Result<DumpSection, ParseError> {
+        let contents = Self::load_located_section(tag, index_row, file)?;
+        Self::check_section(tag, index_row, &contents, last_start, incomplete_sections);
+        Self::parse_located_section(tag, index_row, &contents)
+    }
+
+    fn load_located_section(
+        tag: &Tag,
+        index_row: &IndexRow,
+        file: &File,
+    ) -> Result<String, ParseError> {
+        Self::load_section(index_row, file)
+            .map_err(|err| ParseError::section(tag.clone(), index_row, SectionError::Read(err)))
+    }
+
+    fn parse_located_section(
+        tag: &Tag,
+        index_row: &IndexRow,
+        contents: &str,
+    ) -> Result<DumpSection, ParseError> {
+        parse_section(contents, index_row.id.as_deref())
+            .map_err(|cause| ParseError::section(tag.clone(), index_row, cause))
+    }
+
+    // Counts a loaded section as incomplete if it lacks some of the keys of its tag. The
+    // section a truncated dump stops in, at `last_start`, is counted up front.
+    fn check_section(
+        tag: &Tag,
+        index_row: &IndexRow,
+        contents: &str,
+        last_start: Option<u64>,
+        incomplete_sections: &AtomicUsize,
+    ) {
+        if last_start.is_some() && index_row.start.parse::<u64>().ok() == last_start {
+            return;
+        }
+        if is_section_incomplete(tag, contents) {
+            incomplete_sections.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
     // lines will look like `lA1E:jose_xchacha20_poly1305_crypto|HFFFF4541B8B0`
     // lines that have | denote a continuation of another heap addr
     // l is list, A is atom, H is heap, I is integer, Y is binary, E is heap binary
-    // if we find a heap addr, increment the depth and continue parsing into the main structure
+    // if we find a heap addr, follow it and continue parsing into the main structure
     // if it's a offheap binary, simply just print it out the length
     // something with multiple
 
-    pub fn load_proc_heap(&self, index_row: &IndexRow, file: &File) -> io::Result<Text> {
-        let contents = Self::load_section(index_row, file)?;
-        let mut text = Text::default();
-
-        match parse_section(&contents, index_row.id.as_deref()) {
-            Ok(DumpSection::Generic(proc_heap)) => {
-                proc_heap.raw_lines.into_iter().for_each(|line| {
-                    let parts: Vec<&str> = line.splitn(2, ':').collect();
-
-                    if parts.len() == 2 {
-                        let addr = parts[0];
-                        match self.parse_datatype(parts[1], 0) {
-                            Ok(parsed_res) => {
-                                text.lines.push(Line::from(vec![
-                                    Span::styled(
-                                        format!("{}", addr),
-                                        Style::default().fg(Color::Yellow),
-                                    ),
-                                    Span::raw(" - "),
-                                    Span::styled(
-                                        format!("{}", parsed_res),
-                                        Style::default().fg(Color::Cyan),
-                                    ),
-                                ]));
-                            }
-                            Err(err) => {
-                                text.lines.push(Line::from(vec![
-                                    Span::styled(
-                                        format!("{}", addr),
-                                        Style::default().fg(Color::Yellow),
-                                    ),
-                                    Span::raw(" - "),
-                                    Span::styled(
-                                        format!("{}", err),
-                                        Style::default().fg(Color::Red),
-                                    ),
-                                ]));
-                            }
-                        }
-                    } else {
-                        text.lines.push(Line::from(vec![Span::raw(line)]));
-                    }
-                });
-            }
-            Err(err) => {
-                return Err(io::Error::new(
-                    io::ErrorKind::Other,
-                    format!("Parse error: {}", err),
-                ));
+    pub fn load_proc_heap(
+        &self,
+        index_row: &IndexRow,
+        file: &File,
+    ) -> Result<Vec<HeapTerm>, ParseError> {
+        let contents = Self::load_located_section(&Tag::ProcHeap, index_row, file)?;
+        let mut terms = Vec::new();
+
+        if let DumpSection::Generic(proc_heap) =
+            Self::parse_located_section(&Tag::ProcHeap, index_row, &contents)?
+        {
+            for line in proc_heap.raw_lines {
+                let term = match line.split_once(':') {
+                    Some((addr, data)) => match self.parse_datatype(data) {
+                        Ok(term) => HeapTerm {
+                            address: Some(addr.to_string()),
+                            term,
+                            error: None,
+                        },
+                        Err(err) => HeapTerm {
+                            address: Some(addr.to_string()),
+                            term: Term::Raw(data.to_string()),
+                            error: Some(err.to_string()),
+                        },
+                    },
+                    None => HeapTerm {
+                        address: None,
+                        term: Term::Raw(line),
+                        error: None,
+                    },
+                };
+                terms.push(term);
             }
-            _ => {}
         }
-        Ok(text)
+        Ok(terms)
     }
 
-    pub fn load_proc_stack(&self, index_row: &IndexRow, file: &File) -> io::Result<Text> {
-        let contents = Self::load_section(index_row, file)?;
-        let mut text = Text::default();
+    pub fn load_proc_stack(
+        &self,
+        index_row: &IndexRow,
+        file: &File,
+    ) -> Result<Vec<DecodedFrame>, ParseError> {
+        let contents = Self::load_located_section(&Tag::ProcStack, index_row, file)?;
+        let mut frames = Vec::new();
         let mut addr = String::new();
 
-        if let Ok(DumpSection::ProcStack(proc_stack)) =
-            parse_section(&contents, index_row.id.as_deref())
+        if let DumpSection::ProcStack(proc_stack) =
+            Self::parse_located_section(&Tag::ProcStack, index_row, &contents)?
         {
-            proc_stack.frames.into_iter().for_each(|frame| {
-                let mut current_line_variables = Vec::new();
-                frame.variables.into_iter().for_each(|variable| {
-                    current_line_variables.push(self.parse_datatype(&variable, 0).unwrap());
-                });
-                let line = Line::from(vec![
-                    Span::styled(
-                        format!("{}", frame.address),
-                        Style::default().fg(Color::Yellow),
-                    ),
-                    Span::raw(" - M: "),
-                    Span::styled(
-                        format!("{}", frame.module),
-                        Style::default().fg(Color::Green),
-                    ),
-                    Span::raw(" F: "),
-                    Span::styled(
-                        format!("{}", frame.function),
-                        Style::default().fg(Color::Cyan),
-                    ),
-                    Span::raw(" A: ("),
-                    Span::styled(
-                        current_line_variables.join(","),
-                        Style::default().fg(Color::Magenta),
-                    ),
-                    Span::raw(")"),
-                ]);
+            for frame in proc_stack.frames {
                 // deduplication, if it's the same addr don't add it. Sometimes the frames have weird duplicates
-                if addr != frame.address {
-                    text.lines.push(line);
-                    addr = frame.address.clone();
+                if addr == frame.address {
+                    continue;
                 }
-            });
+                addr = frame.address.clone();
+                let variables = frame
+                    .variables
+                    .into_iter()
+                    .map(|variable| {
+                        self.parse_datatype(&variable)
+                            .unwrap_or(Term::Raw(variable))
+                    })
+                    .collect();
+                frames.push(DecodedFrame {
+                    address: frame.address,
+                    module: frame.module,
+                    function: frame.function,
+                    variables,
+                });
+            }
         }
-        Ok(text)
+        Ok(frames)
     }
 
-    pub fn load_proc_message_queue(&self, index_row: &IndexRow, file: &File) -> io::Result<Text> {
-        let contents = Self::load_section(index_row, file)?;
-        let mut text = Text::default();
-        if let Ok(DumpSection::ProcMessages(proc_messages)) =
-            parse_section(&contents, index_row.id.as_deref())
+    pub fn load_proc_message_queue(
+        &self,
+        index_row: &IndexRow,
+        file: &File,
+    ) -> Result<Vec<DecodedMessage>, ParseError> {
+        let contents = Self::load_located_section(&Tag::ProcMessages, index_row, file)?;
+        let mut messages = Vec::new();
+        if let DumpSection::ProcMessages(proc_messages) =
+            Self::parse_located_section(&Tag::ProcMessages, index_row, &contents)?
         {
-            proc_messages
-                .messages
-                .into_iter()
-                .for_each(|(message_addr, message_val)| {
-                    // set the ADDR to be Yellow and the Value to be Cyan
-                    // and try to parse each data type
-                    let message_addr = self.parse_datatype(&message_addr, 0).unwrap();
-                    let message_val = self.parse_datatype(&message_val, 0).unwrap();
-                    let line = Line::from(vec![
-                        Span::styled(message_addr, Style::default().fg(Color::Yellow)),
-                        Span::raw(" - "),
-                        Span::styled(message_val, Style::default().fg(Color::Cyan)),
-                    ]);
-                    text.lines.push(line);
+            for (message_addr, message_val) in proc_messages.messages {
+                // try to parse each data type, falling back to the raw data
+                messages.push(DecodedMessage {
+                    address: self
+                        .parse_datatype(&message_addr)
+                        .map_or(message_addr, |address| address.to_string()),
+                    message: self
+                        .parse_datatype(&message_val)
+                        .unwrap_or(Term::Raw(message_val)),
                 });
-        } else {
-            return Err(io::Error::new(
-                io::ErrorKind::Other,
-                format!("Parse error: {}", contents),
-            ));
+            }
         }
-        Ok(text)
+        Ok(messages)
     }
 
-    // Parses a data type string from a crash dump.
-    //
-    // This function recursively parses the input string `data` to interpret the encoded data type.
-    // It uses the first character of the string to determine the type and then dispatches to a
-    // specific helper function.
-    //
-    // The `depth` parameter is used to track the recursion depth and prevent infinite loops.
-    //
-    // # Arguments
-    //
-    // * `data` - The input string containing the encoded data type.
-    // * `depth` - The current recursion depth.
-    //
-    // # Returns
-    //
-    // A `Result` containing either a `String` representation of the parsed data type or a `String`
-    // describing the error encountered.
-    //
-    // # Examples
-    //
-    // ```
-    // // Parse an integer:
-    // let result = parse_datatype("I123", 0);
-    // assert_eq!(result, Ok("123".to_string()));
-    //
-    // // Parse a tuple:
-    // let result = parse_datatype("t2:I1,I2", 0);
-    // assert_eq!(result, Ok("{1, 2}".to_string()));
-    // ```
-    fn parse_datatype(&self, data: &str, depth: usize) -> Result<String, String> {
-        if depth > MAX_DEPTH_PARSE_DATATYPE {
-            return Ok(format!("(*{})", data));
+    pub fn load_proc_dictionary(
+        &self,
+        index_row: &IndexRow,
+        file: &File,
+    ) -> Result<Vec<DictionaryEntry>, ParseError> {
+        let contents = Self::load_located_section(&Tag::ProcDictionary, index_row, file)?;
+        let mut entries = Vec::new();
+        if let DumpSection::ProcDictionary(proc_dictionary) =
+            Self::parse_located_section(&Tag::ProcDictionary, index_row, &contents)?
+        {
+            for entry in proc_dictionary.entries {
+                // each entry points to a {Key, Value} tuple on the process heap
+                entries.push(match self.dictionary_entry(&entry) {
+                    Some((key, value)) => DictionaryEntry {
+                        key: Some(self.decode(&key)),
+                        value: self.decode(&value),
+                    },
+                    None => DictionaryEntry {
+                        key: None,
+                        value: self.decode(&entry),
+                    },
+                });
+            }
         }
+        Ok(entries)
+    }
 
-        let depth = depth + 1;
-        // Match the first character of the data string to determine the data type.
-        match data.chars().next() {
-            Some('t') => self.parse_tuple(data, depth), // Tuple
-            Some('A') => Ok(self.parse_atom(data)),     // Atom
-            Some('I') => self.parse_int(data).map(|i| i.to_string()), // Integer
-            Some('N') => Ok("".to_string()),            // Empty list
-            Some('l') => self.parse_list(data, depth),  // List
-            Some('H') => self.parse_heap(data, depth),  // Heap reference
-            Some('E') => self.parse_encoded_term(data), // Encoded term
-            Some('B') => self.parse_bignum(data),       // Bignum
-            Some('F') => self.parse_float(data),        // Float
-            Some('P') | Some('p') => self.parse_pid(data), // Pid or port
-            Some('Y') => self.parse_binary(data),       // Binary
-            Some('M') => Ok(format!("M: {}", data)),    // Map (currently returns raw data)
-            Some('R') => self.parse_funref(data),       // Fun reference
-            Some('S') => Ok(self.parse_string(data)),   // String
-            _ => Ok(format!(
-                "---don't know how to parse {} at depth {}---",
-                data, depth
-            )), // Unknown data type
-        }
+    // Resolves a dictionary entry such as `H14B3B28E0` to the encoded key and value of the
+    // `t2:<key>,<value>` tuple it points to.
+    fn dictionary_entry(&self, entry: &str) -> Option<(String, String)> {
+        let heap_data = self.all_heap_addresses.get(entry.strip_prefix('H')?)?;
+        let elements = heap_data.value().strip_prefix("t2:")?;
+        let (key, value) = elements.split_once(',')?;
+        Some((key.to_string(), value.to_string()))
     }
 
-    // for now treat S as a string
-    fn parse_string(&self, data: &str) -> String {
-        let parts: Vec<&str> = data.splitn(2, ':').collect();
-        if parts.len() > 1 {
-            parts[1].to_string()
-        } else {
-            "".to_string()
+    // Decodes the terms of sections that may point into other sections, such as the literal
+    // area. Called once every section has been loaded.
+    fn decode_deferred_terms(&mut self) {
+        let decoded: Vec<Term> = self
+            .timers
+            .iter()
+            .map(|timer| match timer {
+                InfoOrIndex::Info(timer_info) => self.decode(&timer_info.message),
+                InfoOrIndex::Index(_) => Term::default(),
+            })
+            .collect();
+        for (timer, decoded_message) in self.timers.iter_mut().zip(decoded) {
+            if let InfoOrIndex::Info(timer_info) = timer {
+                timer_info.decoded_message = decoded_message;
+            }
+        }
+
+        // a term that fails to decode is kept as it is in the dump, and reported
+        let mut persistent_terms = std::mem::take(&mut self.persistent_terms);
+        let mut errors = Vec::new();
+        for info in persistent_terms.iter_mut() {
+            if let InfoOrIndex::Info(info) = info {
+                for term in info.terms.iter_mut() {
+                    let mut decode = |data: &str| {
+                        self.try_decode(data).unwrap_or_else(|err| {
+                            if let Some(index_row) = &info.index_row {
+                                errors.push(ParseError::section(
+                                    Tag::PersistentTerms,
+                                    index_row,
+                                    err,
+                                ));
+                            }
+                            Term::Raw(data.to_string())
+                        })
+                    };
+                    term.decoded_key = decode(&term.key);
+                    term.decoded_value = decode(&term.value);
+                    term.size =
+                        self.estimate_term_size(&term.key) + self.estimate_term_size(&term.value);
+                }
+            }
         }
+        self.persistent_terms = persistent_terms;
+        self.errors.extend(errors);
     }
 
-    fn parse_atom(&self, data: &str) -> String {
-        let parts: Vec<&str> = data.splitn(2, ':').collect();
-        if parts.len() > 1 {
-            parts[1].to_string()
-        } else {
-            "".to_string()
-        }
-    }
-
-    // Parses a tuple data type string.
-    //
-    // This function extracts the tuple size from the input string and then recursively parses the
-    // individual elements of the tuple.
-    //
-    // # Arguments
-    //
-    // * `data` - The input string containing the encoded tuple.
-    // * `depth` - The current recursion depth.
-    //
-    // # Returns
-    //
-    // A `Result` containing either a `String` representation of the parsed tuple or a `String`
-    // describing the error encountered.
-    //
-    // # Examples
-    //
-    // ```
-    // let result = parse_tuple("t2:I1,I2", 1);
-    // assert_eq!(result, Ok("{1, 2}".to_string()));
-    // ```
-    fn parse_tuple(&self, data: &str, depth: usize) -> Result<String, String> {
-        let mut chars = data.chars();
-        chars.next(); // Consume 't'
-
-        let mut size_str = String::new();
-        while let Some(c) = chars.next() {
-            // hex for the sizes
-            if c.is_digit(16) {
-                size_str.push(c);
-            } else {
-                if c == ':' {
-                    break;
-                } else {
-                    return Err(format!("Invalid tuple size format: {}", data));
+    /// Estimates the size in words of a term in the heap encoding, following heap pointers.
+    /// Subterms that are shared, as they often are in the literal area, are only counted once.
+    /// Binaries count their data, including off-heap binaries.
+    pub fn estimate_term_size(&self, data: &str) -> usize {
+        let word_size = self.preamble.word_size as usize;
+        let bytes_to_words = |bytes: usize| bytes.div_ceil(word_size);
+        let hex = |s: &str| usize::from_str_radix(s, 16).unwrap_or(0);
+
+        // the word holding the term itself
+        let mut words = 1;
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut pending: Vec<String> = vec![data.to_string()];
+        while let Some(term) = pending.pop() {
+            let Some(tag) = term.chars().next() else {
+                continue;
+            };
+            let rest = &term[tag.len_utf8()..];
+            match tag {
+                'H' if visited.insert(rest.to_string()) => {
+                    if let Some(heap_data) = self.all_heap_addresses.get(rest) {
+                        pending.push(heap_data.value().clone());
+                    }
+                }
+                't' => {
+                    if let Some((arity, elements)) = rest.split_once(':') {
+                        words += 1 + hex(arity);
+                        pending.extend(elements.split(',').map(|e| e.to_string()));
+                    }
+                }
+                'l' => {
+                    words += 2;
+                    if let Some((car, cdr)) = rest.split_once('|') {
+                        pending.push(car.to_string());
+                        pending.push(cdr.to_string());
+                    }
+                }
+                'M' => {
+                    // map headers and key tuples, plus every key and value word
+                    let tokens: Vec<&str> = rest.split([':', ',', '|']).collect();
+                    words += 3 + tokens.len();
+                    pending.extend(
+                        tokens
+                            .into_iter()
+                            .filter(|t| t.starts_with('H'))
+                            .map(|t| t.to_string()),
+                    );
+                }
+                'Y' => {
+                    let mut parts = rest.get(1..).unwrap_or("").split(':');
+                    match rest.chars().next() {
+                        // heap binary: Yh<size>:<data>
+                        Some('h') => words += 2 + bytes_to_words(hex(parts.next().unwrap_or(""))),
+                        // refc and sub binaries: Yc<binary>:<offset>:<size>, counted as
+                        // the ProcBin or ErlSubBin plus the data they refer to
+                        _ => {
+                            let size = parts.nth(2).map_or(0, hex);
+                            words += 6 + bytes_to_words(size);
+                        }
+                    }
+                }
+                'B' => {
+                    let digits = rest.trim_start_matches('-').len();
+                    words += 1 + bytes_to_words(digits.div_ceil(2));
+                }
+                'F' => words += 1 + bytes_to_words(8),
+                'E' => {
+                    let len = rest.split_once(':').map_or(0, |(len, _)| hex(len));
+                    words += bytes_to_words(len);
                 }
+                // immediates take no space besides the word that holds them
+                _ => {}
             }
         }
+        words
+    }
 
-        let remaining_data = chars.as_str();
-        let parts: Vec<&str> = remaining_data.split(',').collect();
-
-        let parsed: Result<Vec<String>, String> = parts
-            .iter()
-            .map(|x| self.parse_datatype(x, depth))
-            .collect();
+    /// Decodes a term written in the crash dump's heap encoding (e.g. `t2:A2:ok,I1`) and
+    /// renders it as text. Terms that are already printed in Erlang syntax, such as timer
+    /// messages, are returned as they are.
+    pub fn decode_term(&self, data: &str) -> String {
+        self.decode(data).to_string()
+    }
 
-        let parsed = parsed?;
-        Ok(format!("{{{}}}", parsed.join(", ")))
+    /// Decodes a term written in the crash dump's heap encoding into a [`Term`], following heap
+    /// pointers. Terms that are already printed in Erlang syntax, or that can't be decoded, are
+    /// kept as a `Term::Raw`.
+    pub fn decode(&self, data: &str) -> Term {
+        self.try_decode(data)
+            .unwrap_or_else(|_| Term::Raw(data.to_string()))
     }
 
-    fn parse_int(&self, data: &str) -> Result<i64, String> {
-        let int_str = &data[1..];
-        int_str.parse::<i64>().map_err(|e| e.to_string())
+    /// Same as `decode`, returning why a term in the heap encoding couldn't be decoded instead
+    /// of keeping it as a `Term::Raw`.
+    pub fn try_decode(&self, data: &str) -> Result<Term, SectionError> {
+        static RE_HEAP_ENCODED: OnceLock<Regex> = OnceLock::new();
+        // Printed terms start with a lowercase atom, a quote, a digit or punctuation, while the
+        // heap encoding starts with a type tag. An unquoted atom can't contain `:`, `|` or `<`.
+        let re = RE_HEAP_ENCODED.get_or_init(|| {
+            Regex::new(
+                r"^(?:[tAEFS][0-9A-Fa-f]+:|I-?\d+$|N$|H[0-9A-Fa-f]+$|l[^|]*\||[Pp]<|Y[hcs][0-9A-Fa-f]|M[fhn]|B-?\d|B-?16#|R|FuA|FeA)",
+            )
+            .unwrap()
+        });
+        if !re.is_match(data) {
+            return Ok(Term::Raw(data.to_string()));
+        }
+        self.parse_datatype(data)
     }
 
-    // Parses a list data type string.
+    // Decodes a term written in the heap encoding, such as `t2:I1,I2`.
     //
-    // This function recursively parses the individual elements of the list.
-    //
-    // # Arguments
-    //
-    // * `data` - The input string containing the encoded list.
-    // * `depth` - The current recursion depth.
-    //
-    // # Returns
-    //
-    // A `Result` containing either a `String` representation of the parsed list or a `String`
-    // describing the error encountered.
+    // The heap walker follows heap pointers with an explicit stack rather than recursing, and
+    // hands everything that doesn't point to other terms to `parse_leaf`. The `decode_limits`
+    // budget bounds how much of the term is decoded, and pointers back into the term are
+    // detected, so a cyclic or huge term can't loop forever.
     //
     // # Examples
     //
     // ```
-    // let result = parse_list("lHFFFF454383C8|HFFFF45438460|N", 1);
-    // assert_eq!(result, Ok("[{1, 2}, {3, 4}]".to_string()));
+    // // Parse an integer:
+    // let result = crash_dump.parse_datatype("I123");
+    // assert_eq!(result, Ok(Term::Integer(123)));
+    //
+    // // Parse a tuple:
+    // let result = crash_dump.parse_datatype("t2:I1,I2");
+    // assert_eq!(result.unwrap().to_string(), "{1, 2}");
     // ```
-    fn parse_list(&self, data: &str, depth: usize) -> Result<String, String> {
-        let parts = data[1..].split('|'); // Remove 'l' and split by '|'
-        let parsed: Result<Vec<String>, String> =
-            parts.map(|x| self.parse_datatype(x, depth)).collect();
-
-        let parsed = parsed?;
-        Ok(format!("[{}]", parsed.join(", ")))
+    fn parse_datatype(&self, data: &str) -> Result<Term, SectionError> {
+        walker::walk(self, data, self.decode_limits)
     }
 
-    // Parses a heap reference data type string.
-    //
-    // This function looks up the heap address in the `all_heap_addresses` map and recursively parses
-    // the corresponding data. If the address is not found, it returns a string indicating an
-    // unresolved heap reference.
-    //
-    // # Arguments
-    //
-    // * `data` - The input string containing the encoded heap reference.
-    // * `depth` - The current recursion depth.
-    //
-    // # Returns
-    //
-    // A `Result` containing either a `String` representation of the parsed heap data or a `String`
-    // describing the error encountered.
-    //
-    // # Examples
-    //
-    // ```
-    // let result = parse_heap("HFFFF454383C8", 1);
-    // assert_eq!(result, Ok("{1, 2}".to_string()));
-    // ```
-    fn parse_heap(&self, data: &str, depth: usize) -> Result<String, String> {
-        let addr = &data[1..]; // Remove 'H'
+    // Decodes a term that doesn't point to others on the heap, as the heap walker does for
+    // everything but tuples, lists, maps and heap pointers.
+    pub(super) fn parse_leaf(&self, data: &str) -> Result<Term, SectionError> {
+        // funs share the `F` tag with floats
+        if data.starts_with("Fu") || data.starts_with("FeA") {
+            return self.parse_fun(data);
+        }
+        // Match the first character of the data string to determine the data type.
+        match data.chars().next() {
+            Some('A') => Ok(Term::Atom(self.parse_atom(data))), // Atom
+            Some('I') => self.parse_int(data).map(Term::Integer), // Integer
+            Some('N') => Ok(Term::List(vec![], None)),          // Empty list
+            Some('E') => self.parse_encoded_term(data),         // Encoded term
+            Some('B') => self.parse_bignum(data),               // Bignum
+            Some('F') => self.parse_float(data),                // Float
+            Some('P') | Some('p') => self.parse_pid(data),      // Pid or port
+            Some('Y') => self.parse_binary(data),               // Binary
+            Some('R') => self.parse_funref(data),               // Fun reference
+            Some('S') => Ok(Term::Raw(self.parse_string(data))), // String
+            _ => Ok(Term::Raw(format!("---don't know how to parse {}---", data))), // Unknown data type
+        }
+    }
 
-        match self.all_heap_addresses.get(addr) {
-            Some(heap_data) => self.parse_datatype(heap_data.value(), depth),
-            None => Ok(format!("*U - {}", addr)),
+    // for now treat S as a string
+    fn parse_string(&self, data: &str) -> String {
+        let parts: Vec<&str> = data.splitn(2, ':').collect();
+        if parts.len() > 1 {
+            parts[1].to_string()
+        } else {
+            "".to_string()
         }
     }
 
-    fn parse_bignum(&self, data: &str) -> Result<String, String> {
-        // let sign = if data.starts_with("B-") { "-" } else { "" };
-        let number_str = if data.starts_with("B16#") || data.starts_with("B-16#") {
-            &data[4..] // Skip "B16#" or "B-16#"
+    fn parse_atom(&self, data: &str) -> String {
+        let parts: Vec<&str> = data.splitn(2, ':').collect();
+        if parts.len() > 1 {
+            parts[1].to_string()
         } else {
-            &data[1..] // Skip "B"
+            "".to_string()
+        }
+    }
+
+    fn parse_int(&self, data: &str) -> Result<i64, SectionError> {
+        let int_str = &data[1..];
+        int_str
+            .parse::<i64>()
+            .map_err(|e| SectionError::InvalidTerm(e.to_string()))
+    }
+
+    // `B<digits>` or `B16#<hex digits>`, with a `-` after the `B` if negative
+    fn parse_bignum(&self, data: &str) -> Result<Term, SectionError> {
+        let invalid = || SectionError::from(format!("Invalid bignum format: {}", data));
+        let number = &data[1..]; // Skip "B"
+        let (sign, magnitude) = match number.strip_prefix('-') {
+            Some(magnitude) => ("-", magnitude),
+            None => ("", number),
+        };
+        let digits = match magnitude.strip_prefix("16#") {
+            Some(hex) => hex_to_decimal(hex).ok_or_else(invalid)?,
+            None if !magnitude.is_empty() && magnitude.bytes().all(|b| b.is_ascii_digit()) => {
+                magnitude.to_string()
+            }
+            None => return Err(invalid()),
         };
-        Ok(format!("[bignum size: {}]", number_str.len()))
+        Ok(Term::Bignum(format!("{}{}", sign, digits)))
     }
 
-    fn parse_float(&self, data: &str) -> Result<String, String> {
+    fn parse_float(&self, data: &str) -> Result<Term, SectionError> {
         let parts: Vec<&str> = data[1..].splitn(2, ':').collect(); // Skip 'F'
         if parts.len() != 2 {
-            return Err(format!("Invalid float format: {}", data));
+            return Err(format!("Invalid float format: {}", data).into());
         }
         let len_str = parts[0];
         let float_str = parts[1];
@@ -1085,288 +1771,286 @@ impl CrashDump {
                 "Float length mismatch: expected {}, got {}",
                 len,
                 float_str.len()
-            ));
+            )
+            .into());
         }
-        Ok(format!("[float: {}]", float_str))
+        let float = float_str
+            .parse::<f64>()
+            .map_err(|e| format!("Invalid float {}: {}", float_str, e))?;
+        Ok(Term::Float(float))
     }
 
-    fn parse_pid(&self, data: &str) -> Result<String, String> {
-        let prefix = match data.chars().next() {
-            Some('P') => "[external pid: ",
-            Some('p') => "[external port: ",
-            _ => return Err(format!("Invalid pid/port format: {}", data)),
-        };
-        Ok(format!("{}{}]", prefix, &data[1..]))
+    fn parse_pid(&self, data: &str) -> Result<Term, SectionError> {
+        let id = data[1..].to_string();
+        let node = self.node_of(&id).map(|node| node.name.clone());
+        match data.chars().next() {
+            Some('P') => Ok(Term::Pid { pid: id, node }),
+            Some('p') => Ok(Term::Port { port: id, node }),
+            _ => Err(format!("Invalid pid/port format: {}", data).into()),
+        }
+    }
+
+    /// Returns the node a pid or port such as `<5.87.0>` lives on.
+    ///
+    /// The first number of a pid printed in a dump is the channel of its node, `0` for the
+    /// local node, so only pids of remote nodes that have a node section resolve.
+    pub fn node_of(&self, pid: &str) -> Option<&NodeInfo> {
+        let channel = pid.strip_prefix('<')?.split('.').next()?;
+        if channel == "0" {
+            return None;
+        }
+        self.nodes.iter().find_map(|node| match node {
+            InfoOrIndex::Info(node) if node.channel == channel => Some(node),
+            _ => None,
+        })
     }
 
-    fn parse_binary(&self, data: &str) -> Result<String, String> {
-        match &data[1..2] {
+    fn parse_binary(&self, data: &str) -> Result<Term, SectionError> {
+        match data.get(1..2).unwrap_or("") {
             "h" => {
-                // Heap binary
-                let binary_data = &data[3..]; // Skip "Yh"
-                Ok(format!("[heap binary: {}]", binary_data))
+                // Heap binary, `Yh<size>:<base64 data>`
+                let (_, binary_data) = data[2..]
+                    .split_once(':')
+                    .ok_or_else(|| format!("Invalid heap binary format: {}", data))?;
+                let bytes = BASE64_STANDARD
+                    .decode(binary_data)
+                    .map_err(|e| format!("Invalid heap binary data: {}", e))?;
+                Ok(Term::Binary(BinaryTerm::Heap(bytes)))
             }
             "c" => {
                 // Reference-counted binary
-                let parts: Vec<&str> = data[2..].split(':').collect(); // Skip "Yc"
-                if parts.len() != 3 {
-                    return Err(format!("Invalid reference-counted binary format: {}", data));
-                }
-                let binp0_str = parts[0];
-                let offset_str = parts[1];
-                let sz_str = parts[2];
-
-                // Parse the values as hexadecimal integers
-                let binp0: usize =
-                    usize::from_str_radix(binp0_str, 16).map_err(|e| e.to_string())?;
-                let offset: usize =
-                    usize::from_str_radix(offset_str, 16).map_err(|e| e.to_string())?;
-                let sz: usize = usize::from_str_radix(sz_str, 16).map_err(|e| e.to_string())?;
-
-                // Lookup in binary index (using self.visited_binaries)
-                let binp_str = format!("{:X}", binp0); // Convert binp0 to hex string
-                match self.visited_binaries.get(&binp_str) {
-                    Some(len) => {
-                        // Found in visited binaries
-                        Ok(format!(
-                            "[ref-counted binary: binp0=0x{:x}, offset={}, sz={}, len={}]",
-                            binp0,
-                            offset,
-                            sz,
-                            len.value()
-                        ))
-                    }
-                    None => {
-                        // Not found in visited binaries
-                        Ok(format!(
-                            "[ref-counted binary: binp0=0x{:x}, offset={}, sz={}, not found]",
-                            binp0, offset, sz
-                        ))
-                    }
-                }
+                let (address, offset, size) = self.parse_binary_reference(data)?;
+                let (offset, head) = self.binary_preview(address, offset, size)?;
+                let (len, bytes) = head.unzip();
+                Ok(Term::Binary(BinaryTerm::Refc {
+                    address,
+                    offset,
+                    size,
+                    len,
+                    bytes,
+                }))
             }
             "s" => {
                 // Sub binary
-                let parts: Vec<&str> = data[2..].split(':').collect(); // Skip "Ys"
-                if parts.len() != 3 {
-                    return Err(format!("Invalid sub binary format: {}", data));
-                }
-                let binp0_str = parts[0];
-                let offset_str = parts[1];
-                let sz_str = parts[2];
-
-                // Parse the values as hexadecimal integers
-                let binp0: usize =
-                    usize::from_str_radix(binp0_str, 16).map_err(|e| e.to_string())?;
-                let offset: usize =
-                    usize::from_str_radix(offset_str, 16).map_err(|e| e.to_string())?;
-                let sz: usize = usize::from_str_radix(sz_str, 16).map_err(|e| e.to_string())?;
-
-                // Dereference the binary (using self.visited_binaries)
-                let binp_str = format!("{:X}", binp0); // Convert binp0 to hex string
-                match self.visited_binaries.get(&binp_str) {
-                    Some(len) => {
-                        // Found in visited binaries
-                        let start = offset; // Assuming offset is the start position
-                        let end = offset + sz; // Assuming sz is the size of the sub binary
-                        if end > *len {
-                            return Err(format!(
-                                "Sub binary out of bounds: start={}, end={}, len={}",
-                                start,
-                                end,
-                                len.value()
-                            ));
-                        }
-                        Ok(format!(
-                            "[sub binary: binp0=0x{:x}, offset={}, sz={}, start={}, end={}]",
-                            binp0, offset, sz, start, end
-                        ))
-                    }
-                    None => {
-                        // Not found in visited binaries
-                        Ok(format!(
-                            "[sub binary: binp0=0x{:x}, offset={}, sz={}, not found]",
-                            binp0, offset, sz
-                        ))
+                let (address, offset, size) = self.parse_binary_reference(data)?;
+                let (offset, head) = self.binary_preview(address, offset, size)?;
+                let (len, bytes) = head.unzip();
+                if let Some(len) = len {
+                    if offset + size > len {
+                        return Err(format!(
+                            "Sub binary out of bounds: start={}, end={}, len={}",
+                            offset,
+                            offset + size,
+                            len
+                        )
+                        .into());
                     }
                 }
+                Ok(Term::Binary(BinaryTerm::Sub {
+                    address,
+                    offset,
+                    size,
+                    len,
+                    bytes,
+                }))
             }
-            _ => Err(format!("Invalid binary type: {}", data)),
+            _ => Err(format!("Invalid binary type: {}", data).into()),
         }
     }
-    fn parse_map(&self, data: &str, depth: usize) -> Result<String, String> {
-        match &data[1..2] {
-            "f" => {
-                // Flatmap
-                let parts: Vec<&str> = data[2..].split(':').collect(); // Skip "Mf"
-                if parts.len() < 2 {
-                    // At least size and one key-value pair
-                    return Err(format!("Invalid flatmap format: {}", data));
-                }
-                let size_str = parts[0];
-                let size: usize = usize::from_str_radix(size_str, 16)
-                    .map_err(|e| format!("{:?}, {}", parts, e.to_string()))?;
-
-                // Recursively parse the key-value pairs
-                let mut key_value_pairs = Vec::new();
-                let mut current_data = parts[1..].join(":"); // Join the remaining parts with ":"
-                for _ in 0..size {
-                    let parts: Vec<&str> = current_data.splitn(2, ':').collect();
-                    if parts.len() != 2 {
-                        return Err(format!(
-                            "Invalid flatmap key-value pair format: {}",
-                            current_data
-                        ));
-                    }
-                    let key_data = parts[0];
-                    let value_data = parts[1];
-                    let key = self.parse_datatype(key_data, depth + 1)?;
-                    let value = self.parse_datatype(value_data, depth + 1)?;
-                    key_value_pairs.push(format!("{}: {}", key, value));
-                    current_data = value_data.to_string();
-                }
 
-                Ok(format!(
-                    "[flatmap: size={}, {{{}}}]",
-                    size,
-                    key_value_pairs.join(", ")
-                ))
-            }
-            "h" => {
-                // Hashmap head node
-                let parts: Vec<&str> = data[3..].split(':').collect(); // Skip "Mh"
-                if parts.len() != 2 {
-                    return Err(format!("Invalid hashmap head node format: {}", data));
-                }
-                let map_size_str = parts[0];
-                let n_str = parts[1];
-                let map_size: usize =
-                    usize::from_str_radix(map_size_str, 16).map_err(|e| e.to_string())?;
-                let n: usize = usize::from_str_radix(n_str, 16).map_err(|e| e.to_string())?;
-
-                // Recursively parse the nodes
-                let mut nodes = Vec::new();
-                let mut current_data = n_str.to_owned();
-                for _ in 0..n {
-                    let parts: Vec<&str> = current_data.splitn(2, '|').collect();
-                    if parts.len() != 2 {
-                        return Err(format!("Invalid hashmap node format: {}", current_data));
-                    }
-                    let node_data = parts[0];
-                    let next_data = parts[1];
-                    let node = self.parse_datatype(node_data, depth + 1)?;
-                    nodes.push(node);
-                    current_data = next_data.to_string();
-                }
+    // Parses the `<binp0>:<offset>:<size>` of a `Yc` or `Ys` binary, all hexadecimal
+    fn parse_binary_reference(&self, data: &str) -> Result<(usize, usize, usize), SectionError> {
+        let parts: Vec<&str> = data[2..].split(':').collect(); // Skip "Yc" or "Ys"
+        if parts.len() != 3 {
+            return Err(format!("Invalid binary format: {}", data).into());
+        }
+        let hex = |s: &str| usize::from_str_radix(s, 16).map_err(|e| e.to_string());
+        Ok((hex(parts[0])?, hex(parts[1])?, hex(parts[2])?))
+    }
+
+    // The offset of the part of a binary a `Yc` or `Ys` term refers to, with the binary's
+    // length and the first bytes of that part if the dump has the binary.
+    //
+    // Newer emulators print the address of the part instead of its offset. The bytes of a
+    // binary start 4 words after its address, which gives back the offset.
+    fn binary_preview(
+        &self,
+        address: usize,
+        offset: usize,
+        size: usize,
+    ) -> Result<(usize, Option<BinaryHead>), SectionError> {
+        let Some(len) = self.binary_len(address)? else {
+            return Ok((offset, None));
+        };
+        let offset = if offset + size > len && offset >= address {
+            (offset - address).saturating_sub(4 * self.preamble.word_size as usize)
+        } else {
+            offset
+        };
+        let (_, head) = self
+            .load_binary_head(address, offset + BINARY_PREVIEW_LEN)?
+            .unwrap_or_default();
+        let bytes = head.iter().skip(offset).take(size).copied().collect();
+        Ok((offset, Some((len, bytes))))
+    }
+
+    // Length of the binary in the `=binary:<address>` section, if the dump has it
+    fn binary_len(&self, address: usize) -> Result<Option<usize>, SectionError> {
+        Ok(self
+            .load_binary_head(address, BINARY_PREVIEW_LEN)?
+            .map(|(len, _)| len))
+    }
 
-                Ok(format!(
-                    "[hashmap head: size={}, nodes=[{}]]",
-                    map_size,
-                    nodes.join(", ")
-                ))
+    // Length and first `len` bytes of the binary in the `=binary:<address>` section, read from
+    // the dump the first time a term refers to it
+    fn load_binary_head(
+        &self,
+        address: usize,
+        len: usize,
+    ) -> Result<Option<BinaryHead>, SectionError> {
+        let key = format!("{:X}", address);
+        if let Some(preview) = self.binary_previews.get(&key) {
+            let (binary_len, head) = preview.value();
+            if head.len() >= len || head.len() == *binary_len {
+                return Ok(Some(preview.value().clone()));
             }
-            "n" => {
-                // Hashmap interior node
-                let parts: Vec<&str> = data[3..].split(':').collect(); // Skip "Mn"
-                if parts.len() != 1 {
-                    return Err(format!("Invalid hashmap interior node format: {}", data));
-                }
-                let n_str = parts[0];
-                let n: usize = usize::from_str_radix(n_str, 16).map_err(|e| e.to_string())?;
-
-                // Recursively parse the nodes
-                let mut nodes = Vec::new();
-                let mut current_data = n_str.to_owned();
-                for _ in 0..n {
-                    let parts: Vec<&str> = current_data.splitn(2, '|').collect();
-                    if parts.len() != 2 {
-                        return Err(format!("Invalid hashmap node format: {}", current_data));
-                    }
-                    let node_data = parts[0];
-                    let next_data = parts[1];
-                    let node = self.parse_datatype(node_data, depth + 1)?;
-                    nodes.push(node);
-                    current_data = next_data.to_string();
-                }
+        }
+        let Some(index_row) = self.binaries.get(&key).map(|row| row.value().clone()) else {
+            return Ok(None);
+        };
+        let file = File::open(&self.file_path).map_err(SectionError::Read)?;
+        // the header line, the size and 4 base64 characters for every 3 bytes
+        let max_len = key.len() + 32 + len.div_ceil(3) * 4;
+        let contents =
+            Self::load_section_head(&index_row, &file, max_len).map_err(SectionError::Read)?;
+        let preview = parse_binary_section(&contents, Some(len))?;
+        self.binary_previews.insert(key, preview.clone());
+        Ok(Some(preview))
+    }
+
+    /// Loads the contents of the `=binary:<address>` section, `None` if the dump doesn't have
+    /// it.
+    pub fn load_binary(&self, address: usize, file: &File) -> Result<Option<Vec<u8>>, ParseError> {
+        let Some(index_row) = self
+            .binaries
+            .get(&format!("{:X}", address))
+            .map(|row| row.value().clone())
+        else {
+            return Ok(None);
+        };
+        let contents = Self::load_located_section(&Tag::Binary, &index_row, file)?;
+        let (_, bytes) = parse_binary_section(&contents, None)
+            .map_err(|cause| ParseError::section(Tag::Binary, &index_row, cause))?;
+        Ok(Some(bytes))
+    }
 
-                Ok(format!("[hashmap interior: nodes=[{}]]", nodes.join(", ")))
+    /// Returns the bytes of a binary term: those of a heap binary, or the part of its
+    /// `=binary` section a ref-counted or sub binary refers to. `None` if the dump doesn't
+    /// have the section.
+    pub fn binary_contents(
+        &self,
+        binary: &BinaryTerm,
+        file: &File,
+    ) -> Result<Option<Vec<u8>>, ParseError> {
+        match binary {
+            BinaryTerm::Heap(bytes) | BinaryTerm::Bits { bytes, .. } => Ok(Some(bytes.clone())),
+            BinaryTerm::Refc {
+                address,
+                offset,
+                size,
+                ..
             }
-            _ => Err(format!("Invalid map type: {}", data)),
+            | BinaryTerm::Sub {
+                address,
+                offset,
+                size,
+                ..
+            } => Ok(self
+                .load_binary(*address, file)?
+                .map(|bytes| bytes.into_iter().skip(*offset).take(*size).collect())),
         }
     }
 
-    fn parse_funref(&self, data: &str) -> Result<String, String> {
-        let ref_id = &data[2..]; // Skip "Rf"
-        Ok(format!("[fun ref: {}]", ref_id))
+    // Parses a fun, `Fu<module>:<index>:<uniq>:<num_free>` for a local fun and
+    // `Fe<module>:<function>:<arity>` for an external one, with atoms as in `A4:init` and
+    // numbers in hex.
+    fn parse_fun(&self, data: &str) -> Result<Term, SectionError> {
+        let invalid = || format!("Invalid fun format: {}", data);
+        let hex = |s: &str| i64::from_str_radix(s, 16).map_err(|_| invalid());
+        if let Some(rest) = data.strip_prefix("Fe") {
+            let (module, rest) = split_atom(rest).ok_or_else(invalid)?;
+            let (function, rest) = split_atom(rest.trim_start_matches(':')).ok_or_else(invalid)?;
+            let arity = hex(rest.trim_start_matches(':'))?;
+            return Ok(Term::Fun(FunTerm::External {
+                module: module.to_string(),
+                function: function.to_string(),
+                arity,
+            }));
+        }
+        let rest = data.strip_prefix("Fu").ok_or_else(invalid)?;
+        let (module, rest) = split_atom(rest).ok_or_else(invalid)?;
+        // the free variables, if any, follow the number of free variables
+        let mut fields = rest.trim_start_matches(':').split([':', ',']);
+        let index = hex(fields.next().unwrap_or(""))?;
+        let uniq = hex(fields.next().unwrap_or(""))?;
+        Ok(Term::Fun(self.local_fun(module, index, uniq, None)))
     }
 
-    fn parse_encoded_term(&self, data: &str) -> Result<String, String> {
-        // Remove the 'E' prefix
-        let data = &data[1..];
+    /// Formats a local fun the way Erlang prints it, `#Fun<Module.Index.Uniq>`, followed by its
+    /// arity when known and its reference count from the fun table.
+    ///
+    /// The dump has no name for the fun, such as `'-start/1-fun-0-'`, so it can't be printed
+    /// as `fun Module:Name/Arity`.
+    pub fn format_fun(&self, module: &str, index: i64, uniq: i64, arity: Option<u32>) -> String {
+        self.local_fun(module, index, uniq, arity).to_string()
+    }
 
-        // Split the data by ':'
-        let parts: Vec<&str> = data.splitn(2, ':').collect();
-        if parts.len() != 2 {
-            return Err(format!("Invalid heap binary format: {}", data));
+    /// A local fun, with its reference count looked up in the fun table.
+    pub fn local_fun(&self, module: &str, index: i64, uniq: i64, arity: Option<u32>) -> FunTerm {
+        FunTerm::Local {
+            module: module.to_string(),
+            index,
+            uniq,
+            arity,
+            refc: self.fun_entry(module, index, uniq).map(|entry| entry.refc),
         }
+    }
 
-        // Extract the length and binary data
-        let len_str = parts[0];
-        // let binary_data = parts[1];
+    /// Returns the `=fun` section of a local fun, identified by its module, index and uniq as
+    /// in `#Fun<Module.Index.Uniq>`.
+    pub fn fun_entry(&self, module: &str, index: i64, uniq: i64) -> Option<&FunInfo> {
+        self.funs
+            .iter()
+            .find(|fun| fun.index == index && fun.uniq == uniq && fun.module == module)
+    }
 
-        // Parse the length as an integer
-        let len: usize = usize::from_str_radix(len_str, 16).map_err(|e| e.to_string())?;
+    // `Rf<addr>` is the address of a fun entry in the emulator, which isn't printed in the
+    // `=fun` sections, so it can't be resolved.
+    fn parse_funref(&self, data: &str) -> Result<Term, SectionError> {
+        let ref_id = data.get(2..).unwrap_or(""); // Skip "Rf"
+        Ok(Term::Fun(FunTerm::Unresolved(ref_id.to_string())))
+    }
 
-        // let decoded_data = match base64::decode(binary_data) {
-        //     Ok(data) => data,
-        //     Err(e) => return Err(format!("Base64 decode error: {}", e)),
-        // };
-
-        // let decoded_str = String::from_utf8_lossy(&decoded_data);
-
-        Ok(format!("<<bin size {}>>", len))
-    }
-
-    // fn parse_list(data: &str, depth: usize) -> Result<String, String> {
-    //     let mut acc = Vec::new();
-    //     let mut truncated = false;
-    //     let mut data = data.to_string();
-    //     loop {
-    //         let rem = &data[1..];
-    //         let parts: Vec<&str> = rem.split('|').collect();
-    //         let part1 = parts[0];
-    //         let p1 = parse_datatype(filename, part1, depth)?;
-    //         acc.push(p1);
-    //         if parts.len() == 2 {
-    //             let part2 = parts[1];
-    //             if part2 == "N" {
-    //                 break;
-    //             }
-    //             let address = &part2[1..];
-    //             if all_heap_addresses.contains_key(address) {
-    //                 visited_heap_addresses.insert(address.to_string());
-    //                 data = all_heap_addresses[address].clone();
-    //             } else {
-    //                 truncated = true;
-    //                 break;
-    //             }
-    //         } else {
-    //             break;
-    //         }
-    //     }
-    //     let result = try_string(&acc);
-    //     let result = match result {
-    //         Ok(s) => s,
-    //         Err(list) => {
-    //             let mut result = list.join(", ");
-    //             if truncated {
-    //                 result.push_str("...< heap truncated >");
-    //             }
-    //             format!("[{}]", result)
-    //         }
-    //     };
-    //     Ok(result)
-    // }
+    // `E<size>:<base64>` is a term in the external term format, `size` bytes long once decoded.
+    fn parse_encoded_term(&self, data: &str) -> Result<Term, SectionError> {
+        let (len, encoded) = data[1..]
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid external term format: {}", data))?;
+        let len = usize::from_str_radix(len, 16).map_err(|e| e.to_string())?;
+        let bytes = BASE64_STANDARD
+            .decode(encoded)
+            .map_err(|e| format!("Base64 decode error: {}", e))?;
+        if bytes.len() != len {
+            return Err(format!(
+                "External term is {} bytes, expected {}: {}",
+                bytes.len(),
+                len,
+                data
+            )
+            .into());
+        }
+        etf::decode(self, &bytes)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -1381,6 +2065,37 @@ pub struct Preamble {
     pub word_size: u8, // 4 bytes for 32-bit, 8 bytes for 64-bit
 }
 
+impl FromGenericSection for Preamble {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+        // if the `System version` section has "[64-bit]", word size is 8 bytes, otherwise 4
+        let system_version = data.get("System version").cloned().unwrap_or_default();
+        let word_size_local = if system_version.contains("[64-bit]") {
+            8
+        } else {
+            4
+        };
+
+        Ok(Preamble {
+            version: section.id.clone().unwrap_or_default(),
+            time: section.raw_lines.first().cloned().unwrap_or_default(),
+            slogan: data.get("Slogan").cloned().unwrap_or_default(),
+            erts: system_version,
+            taints: data.get("Taints").cloned().unwrap_or_default(),
+            atom_count: data
+                .get("Atoms")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            calling_thread: data.get("Calling Thread").cloned().unwrap_or_default(),
+            word_size: word_size_local,
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Preamble(self)
+    }
+}
+
 impl Preamble {
     pub fn format(&self) -> String {
         format!(
@@ -1408,23 +2123,31 @@ impl MemoryInfo {
             self.total, self.processes, self.system, self.atom, self.binary, self.code, self.ets
         )
     }
+}
 
-    pub fn from_generic_section(data: &HashMap<String, String>) -> Self {
-        MemoryInfo {
-            total: data["total"].parse().unwrap(),
+impl FromGenericSection for MemoryInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+        let get = |key: &str| data.get(key).and_then(|s| s.parse().ok()).unwrap_or(0);
+        Ok(MemoryInfo {
+            total: get("total"),
             processes: Processes {
-                total: data["processes"].parse().unwrap(),
-                used: data["processes_used"].parse().unwrap(),
+                total: get("processes"),
+                used: get("processes_used"),
             },
-            system: data["system"].parse().unwrap(),
+            system: get("system"),
             atom: Atom {
-                total: data["atom"].parse().unwrap(),
-                used: data["atom_used"].parse().unwrap(),
+                total: get("atom"),
+                used: get("atom_used"),
             },
-            binary: data["binary"].parse().unwrap(),
-            code: data["code"].parse().unwrap(),
-            ets: data["ets"].parse().unwrap(),
-        }
+            binary: get("binary"),
+            code: get("code"),
+            ets: get("ets"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Memory(self)
     }
 }
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -1489,189 +2212,6 @@ pub struct ProcInfo {
 }
 
 impl ProcInfo {
-    pub fn format_as_ratatui_text(&self) -> Text {
-        // format as a ratatui text, composed of different lines. Each value should have a colorized key and values
-        // key should be yellow, value should be cyan
-        let mut text = Text::default();
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Pid: ", Style::default().fg(Color::Yellow)),
-            Span::styled(self.pid.clone(), Style::default().fg(Color::Cyan)),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("State: ", Style::default().fg(Color::Yellow)),
-            Span::styled(self.state.clone(), Style::default().fg(Color::Cyan)),
-        ]));
-
-        if let Some(name) = &self.name {
-            text.lines.push(Line::from(vec![
-                Span::styled("Name: ", Style::default().fg(Color::Yellow)),
-                Span::styled(name.clone(), Style::default().fg(Color::Cyan)),
-            ]));
-        }
-
-        if let Some(spawned_as) = &self.spawned_as {
-            text.lines.push(Line::from(vec![
-                Span::styled("Spawned As: ", Style::default().fg(Color::Yellow)),
-                Span::styled(spawned_as.clone(), Style::default().fg(Color::Cyan)),
-            ]));
-        }
-
-        if let Some(spawned_by) = &self.spawned_by {
-            text.lines.push(Line::from(vec![
-                Span::styled("Spawned By: ", Style::default().fg(Color::Yellow)),
-                Span::styled(spawned_by.clone(), Style::default().fg(Color::Cyan)),
-            ]));
-        }
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Message Queue Length: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{}", self.message_queue_length),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled(
-                "Number of Heap Fragments: ",
-                Style::default().fg(Color::Yellow),
-            ),
-            Span::styled(
-                format!("{}", self.number_of_heap_fragments),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Heap Fragment Data: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{}", self.heap_fragment_data),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Reductions: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{}", self.reductions),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Stack Heap: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{}", self.stack_heap),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Old Heap: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{} ({})", human_bytes(self.old_heap), self.old_heap),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Heap Unused: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{} ({})", human_bytes(self.heap_unused), self.heap_unused),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Old Heap Unused: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!(
-                    "{} ({})",
-                    human_bytes(self.old_heap_unused),
-                    self.old_heap_unused
-                ),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Bin Vheap: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{} ({})", human_bytes(self.bin_vheap), self.bin_vheap),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Old Bin Vheap: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!(
-                    "{} ({})",
-                    human_bytes(self.old_bin_vheap),
-                    self.old_bin_vheap
-                ),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Bin Vheap Unused: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!(
-                    "{} ({})",
-                    human_bytes(self.bin_vheap_unused),
-                    self.bin_vheap_unused
-                ),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Old Bin Vheap Unused: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!(
-                    "{} ({})",
-                    human_bytes(self.old_bin_vheap_unused),
-                    self.old_bin_vheap_unused
-                ),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Memory: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{} ({})", human_bytes(self.memory), self.memory),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Arity: ", Style::default().fg(Color::Yellow)),
-            Span::styled(format!("{}", self.arity), Style::default().fg(Color::Cyan)),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Program Counter: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{:?}", self.program_counter),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text.lines.push(Line::from(vec![
-            Span::styled("Internal State: ", Style::default().fg(Color::Yellow)),
-            Span::styled(
-                format!("{:?}", self.internal_state),
-                Style::default().fg(Color::Cyan),
-            ),
-        ]));
-
-        text
-    }
-
     pub fn format(&self) -> String {
         format!(
             "Pid: {}\nState: {}\nName: {:#?}\nSpawned As: {:#?}\nSpawned By: {:#?}\nMessage Queue Length: {}\nNumber of Heap Fragments: {}\nHeap Fragment Data: {}\nLink List: {:#?}\nReductions: {}\nStack Heap: {}\nOld Heap: {}\nHeap Unused: {}\nOld Heap Unused: {}\nBin Vheap: {}\nOld Bin Vheap: {}\nBin Vheap
@@ -1714,31 +2254,33 @@ Unused: {}\nOld Bin Vheap Unused: {}\nMemory: {}\nArity: {}\n{:#?}\nInternal Sta
         ]
     }
 
-    pub fn ref_array(&self) -> [String; 9] {
+    pub fn ref_array(&self, word_size: u8) -> [String; 9] {
         [
             format!("{}", self.old_bin_vheap),
             self.pid.clone(),
             self.name.clone().unwrap_or_default(),
-            human_bytes(self.memory),
-            human_bytes(self.bin_vheap + self.old_bin_vheap),
-            human_bytes(self.bin_vheap),
-            human_bytes(self.bin_vheap_unused),
-            human_bytes(self.old_bin_vheap),
-            human_bytes(self.old_bin_vheap_unused),
+            human_bytes(self.memory, word_size),
+            human_bytes(self.bin_vheap + self.old_bin_vheap, word_size),
+            human_bytes(self.bin_vheap, word_size),
+            human_bytes(self.bin_vheap_unused, word_size),
+            human_bytes(self.old_bin_vheap, word_size),
+            human_bytes(self.old_bin_vheap_unused, word_size),
         ]
     }
 
-    pub fn summary_ref_array(&self) -> [String; 5] {
+    pub fn summary_ref_array(&self, word_size: u8) -> [String; 5] {
         [
             self.pid.clone(),
             self.name.clone().unwrap_or_default(),
-            human_bytes(self.memory),
+            human_bytes(self.memory, word_size),
             format!("{}", self.reductions),
             format!("{}", self.message_queue_length),
         ]
     }
+}
 
-    pub fn from_generic_section(section: &GenericSection) -> Self {
+impl FromGenericSection for ProcInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
         let id = section.id.clone().unwrap_or_default();
         let raw_lines = &section.raw_lines;
         let data = &section.data;
@@ -1854,7 +2396,11 @@ Unused: {}\nOld Bin Vheap Unused: {}\nMemory: {}\nArity: {}\n{:#?}\nInternal Sta
         };
 
         proc.total_bin_vheap = proc.bin_vheap + proc.old_bin_vheap;
-        proc
+        Ok(proc)
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Proc(self)
     }
 }
 
@@ -1894,23 +2440,37 @@ impl GroupInfo {
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct ProgramCounter {
     pub address: String,
+    pub module: String,
     pub function: String,
     pub offset: i64,
     pub arity: i32,
 }
 
 impl ProgramCounter {
+    // Parses a program counter such as `0x000000014536d77c (init:loop/1 + 76)`.
     pub fn from_string(s: &str) -> Option<Self> {
-        let re =
-            Regex::new(r"Program counter: (0x[0-9a-fA-F]+) \(([^:]+):([^/]+)/(\d+) \+ (\d+)\)")
-                .unwrap();
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| {
+            Regex::new(r"(0x[0-9a-fA-F]+) \(([^:]+):(.+)/(\d+) \+ (\d+)\)").unwrap()
+        });
         re.captures(s).map(|caps| ProgramCounter {
             address: caps[1].to_string(),
-            function: caps[2].to_string(),
+            module: caps[2].to_string(),
+            function: caps[3].to_string(),
             offset: caps[5].parse().unwrap_or_default(),
             arity: caps[4].parse().unwrap_or_default(),
         })
     }
+
+    pub fn format(&self) -> String {
+        if self.address.is_empty() {
+            return "".to_string();
+        }
+        format!(
+            "{} ({}:{}/{} + {})",
+            self.address, self.module, self.function, self.arity, self.offset
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -1946,103 +2506,131 @@ pub enum ValueType {
     PidValue,
 }
 
+// The decoded contents of a process, as returned by the `CrashDump::load_proc_*` functions
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct DecodedFrame {
+    // The address of the stack frame (the 0x... line).
+    pub address: String,
+    pub module: String,
+    pub function: String,
+    // The decoded values of the variables in the frame.
+    pub variables: Vec<Term>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct HeapTerm {
+    // None for a line that isn't `<address>:<term>`, which is kept as is in `term`.
+    pub address: Option<String>,
+    // The decoded term, or the encoded one as a `Term::Raw` if it couldn't be decoded.
+    pub term: Term,
+    // Why the term couldn't be decoded.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct DecodedMessage {
+    pub address: String,
+    pub message: Term,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct DictionaryEntry {
+    // None if the entry doesn't point to a {Key, Value} tuple, it is then shown as the value.
+    pub key: Option<Term>,
+    pub value: Term,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct ProcStackInfo {
     pub pid: String,
     pub frames: Vec<StackFrame>,
 }
 
-impl ProcStackInfo {
-    pub fn from_generic_section(section: &GenericSection) -> Result<Self, String> {
+impl FromGenericSection for ProcStackInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
         if section.tag != "proc_stack" {
-            return Err("Not a proc_stack section".to_string());
+            return Err(SectionError::Malformed(
+                "Not a proc_stack section".to_string(),
+            ));
         }
-        let mut total_frames = Vec::new();
-        let mut current_frame_args = Vec::new();
 
-        let mut frame_address = String::new();
-        let mut return_addr = String::new();
-        let mut function = String::new();
-        let mut module = String::new();
-        let mut offset = 0;
-        let mut arity = 0;
+        Ok(ProcStackInfo {
+            pid: section.id.clone().unwrap_or_default(),
+            frames: parse_stack_frames(&section.raw_lines),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::ProcStack(self)
+    }
+}
 
-        // More generic regex to capture function info.
-        let re_func_info = Regex::new(
+/// Parses the lines of a stack dump into frames.
+///
+/// Each `0x...:SReturn addr` (or `SCatch`) line starts a new frame, and the `y` register lines
+/// that follow it are collected as the frame's variables. Any other lines are ignored, which lets
+/// this be used both for `=proc_stack` sections and for the limited stack trace of a
+/// scheduler's current process.
+pub fn parse_stack_frames(lines: &[String]) -> Vec<StackFrame> {
+    static RE_FUNC_INFO: OnceLock<Regex> = OnceLock::new();
+    static RE_NO_MODULE: OnceLock<Regex> = OnceLock::new();
+
+    // More generic regex to capture function info.
+    let re_func_info = RE_FUNC_INFO.get_or_init(|| {
+        Regex::new(
             r"^(?P<address>0x[0-9A-Fa-f]+):S(?:Return addr|Catch)\s+(?P<retaddr>0x[0-9A-Fa-f]+)\s+\((?P<module>[^:]+):(?P<function>[^/]+)/(?P<arity>\d+)\s*\+\s*(?P<offset>\d+)\)",
-        ).unwrap();
-        // Regex to capture cases where there isn't a module e.g. <terminate process normally>
-        let re_no_module = Regex::new(
+        )
+        .unwrap()
+    });
+    // Regex to capture cases where there isn't a module e.g. <terminate process normally>
+    let re_no_module = RE_NO_MODULE.get_or_init(|| {
+        Regex::new(
             r"^(?P<address>0x[0-9A-Fa-f]+):S(?:Return addr|Catch)\s+(?P<retaddr>0x[0-9A-Fa-f]+)\s+\((?P<function><[^>]+>)\)",
-        ).unwrap();
-
-        let mut current_frame = StackFrame {
-            address: frame_address.clone(),
-            return_addr: return_addr.clone(),
-            function: function.clone(),
-            module: module.clone(),
-            offset,
-            arity,
-            variables: current_frame_args.clone(),
-        };
+        )
+        .unwrap()
+    });
 
-        for line in &section.raw_lines {
-            // iterate through the lines, decoding and collecting the values as you go
-            // once we hit a 0x line, we have a frame, so pop whatever we had before that into a string
-            if line.starts_with("y") {
-                let parts: Vec<&str> = line.splitn(2, ":").collect();
-                if parts.len() == 2 {
-                    let arg_value = parts[1].trim().to_string();
-                    current_frame_args.push(arg_value);
-                }
-            } else if line.starts_with("0x") {
-                // push the previous frame into the total_frames vector
-                current_frame.variables = current_frame_args.clone(); // Add the arguments to the current frame
-                total_frames.push(current_frame.clone()); // Push the current frame into the total_frames vector
-                current_frame_args = Vec::new(); // Reset the current frame arguments
-
-                // Try the no-module regex first
-                if let Some(caps) = re_no_module.captures(line) {
-                    frame_address = caps.name("address").unwrap().as_str().to_string();
-                    return_addr = caps.name("retaddr").unwrap().as_str().to_string();
-                    function = caps.name("function").unwrap().as_str().to_string();
-                    // No module, arity, or offset in this case.
-                } else if let Some(caps) = re_func_info.captures(line) {
-                    frame_address = caps.name("address").unwrap().as_str().to_string();
-                    return_addr = caps.name("retaddr").unwrap().as_str().to_string();
-                    function = caps.name("function").unwrap().as_str().to_string();
-                    module = caps.name("module").unwrap().as_str().to_string();
-                    arity = caps
-                        .name("arity")
-                        .unwrap()
-                        .as_str()
-                        .parse::<usize>()
-                        .unwrap();
-                    offset = caps
-                        .name("offset")
-                        .unwrap()
-                        .as_str()
-                        .parse::<usize>()
-                        .unwrap();
-                }
+    let mut total_frames = Vec::new();
+    let mut current_frame = StackFrame::default();
 
-                current_frame = StackFrame {
-                    address: frame_address.clone(),
-                    return_addr: return_addr.clone(),
-                    function: function.clone(),
-                    module: module.clone(),
-                    offset,
-                    arity,
-                    variables: current_frame_args.clone(),
-                };
+    for line in lines {
+        // iterate through the lines, decoding and collecting the values as you go
+        // once we hit a 0x line, we have a frame, so pop whatever we had before that into a string
+        if line.starts_with("y") {
+            let parts: Vec<&str> = line.splitn(2, ":").collect();
+            if parts.len() == 2 {
+                current_frame.variables.push(parts[1].trim().to_string());
+            }
+        } else if line.starts_with("0x") {
+            // push the previous frame, skipping the leading placeholder if it has no variables
+            if !current_frame.address.is_empty() || !current_frame.variables.is_empty() {
+                total_frames.push(current_frame);
             }
-        }
 
-        Ok(ProcStackInfo {
-            pid: section.id.clone().unwrap(),
-            frames: total_frames,
-        })
+            let mut frame = StackFrame::default();
+            // Try the no-module regex first
+            if let Some(caps) = re_no_module.captures(line) {
+                frame.address = caps["address"].to_string();
+                frame.return_addr = caps["retaddr"].to_string();
+                frame.function = caps["function"].to_string();
+                // No module, arity, or offset in this case.
+            } else if let Some(caps) = re_func_info.captures(line) {
+                frame.address = caps["address"].to_string();
+                frame.return_addr = caps["retaddr"].to_string();
+                frame.function = caps["function"].to_string();
+                frame.module = caps["module"].to_string();
+                frame.arity = caps["arity"].parse::<usize>().unwrap_or(0);
+                frame.offset = caps["offset"].parse::<usize>().unwrap_or(0);
+            }
+            current_frame = frame;
+        }
     }
+    if !current_frame.address.is_empty() || !current_frame.variables.is_empty() {
+        total_frames.push(current_frame);
+    }
+
+    total_frames
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
@@ -2051,11 +2639,38 @@ pub struct ProcMessagesInfo {
     pub messages: HashMap<String, String>,
 }
 
-// ProcMessages are arranged with <ADDR>:<VALUE> format, we can just parse .data
-impl ProcMessagesInfo {
-    fn from_generic_section(section: &GenericSection) -> Result<Self, String> {
-        if section.tag != TAG_PROC_MESSAGES {
-            return Err("Not a proc_messages section".to_string());
+// The process dictionary, one `{Key, Value}` entry per line, in the heap encoding.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct ProcDictionaryInfo {
+    pub pid: String,
+    pub entries: Vec<String>,
+}
+
+impl FromGenericSection for ProcDictionaryInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        Ok(ProcDictionaryInfo {
+            pid: section.id.clone().unwrap_or_default(),
+            entries: section
+                .raw_lines
+                .iter()
+                .filter(|line| !line.is_empty())
+                .cloned()
+                .collect(),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::ProcDictionary(self)
+    }
+}
+
+// ProcMessages are arranged with <ADDR>:<VALUE> format, we can just parse .data
+impl FromGenericSection for ProcMessagesInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        if section.tag != TAG_PROC_MESSAGES {
+            return Err(SectionError::Malformed(
+                "Not a proc_messages section".to_string(),
+            ));
         }
         let mut messages = HashMap::new();
         section.raw_lines.iter().for_each(|line| {
@@ -2066,10 +2681,14 @@ impl ProcMessagesInfo {
         });
 
         Ok(ProcMessagesInfo {
-            pid: section.id.clone().unwrap(),
+            pid: section.id.clone().unwrap_or_default(),
             messages,
         })
     }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::ProcMessages(self)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
@@ -2092,39 +2711,51 @@ pub struct StackFrame {
 
 // the following structs are not strictly parsed yet, although support for them is ongoing
 
-// Allocator information (currently unimplemented).
+// Allocator information, parsed from `=allocator:<name>[<instance>]` sections.
 //
-// This struct will eventually hold information about the memory allocator used by the Erlang VM.
-#[derive(Debug)]
+// Only the alloc_util allocators (binary_alloc, eheap_alloc, ...) have carriers and blocks;
+// the others (mseg_alloc, sys_alloc, erts_mmap, ...) only fill `options` and `data`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct AllocatorInfo {
+    // Allocator type, e.g. `binary_alloc`
     pub name: String,
+    // Instance number, `None` for allocators that only have one instance
+    pub instance: Option<i32>,
     pub version: String,
     pub options: HashMap<String, String>,
+    // Blocks keyed by the allocator type that owns them (OTP 22+ reports blocks per origin)
     pub mbcs_blocks: HashMap<String, BlockInfo>,
     pub mbcs_carriers: MBCSCarriers,
     pub sbcs_blocks: HashMap<String, BlockInfo>,
     pub sbcs_carriers: SBCSCarriers,
     pub calls: Calls,
+    // Every other `key: value` line of the section, e.g. the mseg_alloc segment counters
+    pub data: HashMap<String, String>,
 }
-#[derive(Debug)]
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct MBCSCarriers {
-    pub count: i64,
+    // count and size are [current, max since last call, max]
+    pub count: [i64; 3],
     pub mseg_count: i64,
     pub sys_alloc_count: i64,
     pub size: [i64; 3],
     pub mseg_size: i64,
     pub sys_alloc_size: i64,
 }
-#[derive(Debug)]
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct SBCSCarriers {
-    pub count: i64,
+    // count and size are [current, max since last call, max]
+    pub count: [i64; 3],
     pub mseg_count: i64,
     pub sys_alloc_count: i64,
     pub size: [i64; 3],
     pub mseg_size: i64,
     pub sys_alloc_size: i64,
 }
-#[derive(Debug)]
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct Calls {
     pub alloc: i64,
     pub free: i64,
@@ -2136,36 +2767,895 @@ pub struct Calls {
     pub sys_free: i64,
     pub sys_realloc: i64,
 }
-#[derive(Debug)]
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct BlockInfo {
+    // [current, max since last call, max]
     pub count: [i64; 3],
     pub size: [i64; 3],
 }
 
-// Node information (currently unimplemented).
-//
-// This struct will eventually hold information about the Erlang node involved in the crash.
-#[derive(Debug)]
+// Carrier utilization below this ratio marks an allocator as a fragmentation suspect.
+pub const FRAGMENTATION_UTILIZATION_THRESHOLD: f64 = 0.5;
+// Allocators with less than this many bytes in carriers are never flagged, as a mostly empty
+// carrier of a few KB is not worth looking at.
+pub const FRAGMENTATION_MIN_CARRIERS_SIZE: i64 = 8 * 1024 * 1024;
+
+impl AllocatorInfo {
+    // Current size of all blocks in multiblock carriers, in bytes
+    pub fn mbcs_blocks_size(&self) -> i64 {
+        self.mbcs_blocks.values().map(|block| block.size[0]).sum()
+    }
+
+    // Current size of all blocks in singleblock carriers, in bytes
+    pub fn sbcs_blocks_size(&self) -> i64 {
+        self.sbcs_blocks.values().map(|block| block.size[0]).sum()
+    }
+
+    pub fn blocks_size(&self) -> i64 {
+        self.mbcs_blocks_size() + self.sbcs_blocks_size()
+    }
+
+    pub fn carriers_size(&self) -> i64 {
+        self.mbcs_carriers.size[0] + self.sbcs_carriers.size[0]
+    }
+
+    // Blocks size / carriers size, `None` if the allocator has no carriers
+    pub fn utilization(&self) -> Option<f64> {
+        utilization(self.blocks_size(), self.carriers_size())
+    }
+}
+
+impl FromGenericSection for AllocatorInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        static RE_ID: OnceLock<Regex> = OnceLock::new();
+        static RE_BLOCKS: OnceLock<Regex> = OnceLock::new();
+        let re_id = RE_ID
+            .get_or_init(|| Regex::new(r"^(?P<name>[^\[]+)(?:\[(?P<instance>\d+)\])?$").unwrap());
+        // `mbcs blocks[binary_alloc] count` on OTP 22+, `mbcs blocks` and `mbcs blocks size` before
+        let re_blocks = RE_BLOCKS.get_or_init(|| {
+            Regex::new(r"^(?P<kind>mbcs|sbcs) blocks(?:\[(?P<origin>[^\]]+)\])?(?: (?P<field>count|size))?$")
+                .unwrap()
+        });
+
+        let id = section.id.clone().unwrap_or_default();
+        let (name, instance) = match re_id.captures(&id) {
+            Some(caps) => (
+                caps["name"].to_string(),
+                caps.name("instance")
+                    .and_then(|instance| instance.as_str().parse::<i32>().ok()),
+            ),
+            None => (id.clone(), None),
+        };
+        // `binary_alloc calls`, `binary_free calls` and `binary_realloc calls`
+        let call_prefix = name.strip_suffix("_alloc").unwrap_or(&name).to_string();
+
+        let mut allocator = AllocatorInfo {
+            name,
+            instance,
+            ..Default::default()
+        };
+
+        for (key, value) in section.data.iter() {
+            if key == "versions" || key == "version" {
+                allocator.version = value.clone();
+            } else if let Some(option) = key.strip_prefix("option ") {
+                allocator.options.insert(option.to_string(), value.clone());
+            } else if let Some(caps) = re_blocks.captures(key) {
+                let origin = caps
+                    .name("origin")
+                    .map_or(allocator.name.clone(), |origin| origin.as_str().to_string());
+                let blocks = if &caps["kind"] == "mbcs" {
+                    &mut allocator.mbcs_blocks
+                } else {
+                    &mut allocator.sbcs_blocks
+                };
+                let block_info = blocks.entry(origin).or_default();
+                match caps.name("field").map(|field| field.as_str()) {
+                    Some("size") => block_info.size = parse_triple(value),
+                    _ => block_info.count = parse_triple(value),
+                }
+            } else {
+                let single = || parse_triple(value)[0];
+                match key.as_str() {
+                    "mbcs carriers" => allocator.mbcs_carriers.count = parse_triple(value),
+                    "mbcs mseg carriers" => allocator.mbcs_carriers.mseg_count = single(),
+                    "mbcs sys_alloc carriers" => allocator.mbcs_carriers.sys_alloc_count = single(),
+                    "mbcs carriers size" => allocator.mbcs_carriers.size = parse_triple(value),
+                    "mbcs mseg carriers size" => allocator.mbcs_carriers.mseg_size = single(),
+                    "mbcs sys_alloc carriers size" => {
+                        allocator.mbcs_carriers.sys_alloc_size = single()
+                    }
+                    "sbcs carriers" => allocator.sbcs_carriers.count = parse_triple(value),
+                    "sbcs mseg carriers" => allocator.sbcs_carriers.mseg_count = single(),
+                    "sbcs sys_alloc carriers" => allocator.sbcs_carriers.sys_alloc_count = single(),
+                    "sbcs carriers size" => allocator.sbcs_carriers.size = parse_triple(value),
+                    "sbcs mseg carriers size" => allocator.sbcs_carriers.mseg_size = single(),
+                    "sbcs sys_alloc carriers size" => {
+                        allocator.sbcs_carriers.sys_alloc_size = single()
+                    }
+                    "mseg_alloc calls" => allocator.calls.mseg_alloc = single(),
+                    "mseg_dealloc calls" => allocator.calls.mseg_dealloc = single(),
+                    "mseg_realloc calls" => allocator.calls.mseg_realloc = single(),
+                    "sys_alloc calls" => allocator.calls.sys_alloc = single(),
+                    "sys_free calls" => allocator.calls.sys_free = single(),
+                    "sys_realloc calls" => allocator.calls.sys_realloc = single(),
+                    k if k == format!("{}_alloc calls", call_prefix) => {
+                        allocator.calls.alloc = single()
+                    }
+                    k if k == format!("{}_free calls", call_prefix) => {
+                        allocator.calls.free = single()
+                    }
+                    k if k == format!("{}_realloc calls", call_prefix) => {
+                        allocator.calls.realloc = single()
+                    }
+                    _ => {
+                        allocator.data.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(allocator)
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Allocator(self)
+    }
+}
+
+// Parses the `current max_since_last max` triples used by allocator sections. Single values
+// fill the first slot.
+fn parse_triple(value: &str) -> [i64; 3] {
+    let mut triple = [0; 3];
+    for (slot, part) in triple.iter_mut().zip(value.split_whitespace()) {
+        *slot = part.parse::<i64>().unwrap_or(0);
+    }
+    triple
+}
+
+fn utilization(blocks_size: i64, carriers_size: i64) -> Option<f64> {
+    if carriers_size > 0 {
+        Some(blocks_size as f64 / carriers_size as f64)
+    } else {
+        None
+    }
+}
+
+// All instances of one allocator type added together, e.g. every `binary_alloc[N]`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct AllocatorSummary {
+    pub name: String,
+    pub instances: usize,
+    pub mbcs_carriers_count: i64,
+    pub mbcs_carriers_size: i64,
+    pub mbcs_blocks_size: i64,
+    pub sbcs_carriers_count: i64,
+    pub sbcs_carriers_size: i64,
+    pub sbcs_blocks_size: i64,
+}
+
+impl AllocatorSummary {
+    // Aggregates the allocators by type, keeping only types that have carriers. Sorted by
+    // carriers size, largest first.
+    pub fn from_allocators<'a>(
+        allocators: impl IntoIterator<Item = &'a AllocatorInfo>,
+    ) -> Vec<AllocatorSummary> {
+        let mut summaries: HashMap<&str, AllocatorSummary> = HashMap::new();
+        for allocator in allocators {
+            let summary =
+                summaries
+                    .entry(allocator.name.as_str())
+                    .or_insert_with(|| AllocatorSummary {
+                        name: allocator.name.clone(),
+                        ..Default::default()
+                    });
+            summary.instances += 1;
+            summary.mbcs_carriers_count += allocator.mbcs_carriers.count[0];
+            summary.mbcs_carriers_size += allocator.mbcs_carriers.size[0];
+            summary.mbcs_blocks_size += allocator.mbcs_blocks_size();
+            summary.sbcs_carriers_count += allocator.sbcs_carriers.count[0];
+            summary.sbcs_carriers_size += allocator.sbcs_carriers.size[0];
+            summary.sbcs_blocks_size += allocator.sbcs_blocks_size();
+        }
+
+        let mut summaries: Vec<AllocatorSummary> = summaries
+            .into_values()
+            .filter(|summary| summary.carriers_size() > 0)
+            .collect();
+        summaries.sort_by(|a, b| {
+            b.carriers_size()
+                .cmp(&a.carriers_size())
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        summaries
+    }
+
+    pub fn blocks_size(&self) -> i64 {
+        self.mbcs_blocks_size + self.sbcs_blocks_size
+    }
+
+    pub fn carriers_size(&self) -> i64 {
+        self.mbcs_carriers_size + self.sbcs_carriers_size
+    }
+
+    pub fn utilization(&self) -> Option<f64> {
+        utilization(self.blocks_size(), self.carriers_size())
+    }
+
+    // Lots of carrier memory that is mostly unused, the usual sign of allocator fragmentation
+    pub fn is_fragmentation_suspect(&self) -> bool {
+        self.carriers_size() >= FRAGMENTATION_MIN_CARRIERS_SIZE
+            && self
+                .utilization()
+                .is_some_and(|utilization| utilization < FRAGMENTATION_UTILIZATION_THRESHOLD)
+    }
+
+    pub fn headers() -> [&'static str; 9] {
+        [
+            "Allocator",
+            "Instances",
+            "Carriers",
+            "Carriers Size",
+            "Blocks Size",
+            "Utilization",
+            "MBCS Util",
+            "SBCS Util",
+            "Fragmentation",
+        ]
+    }
+
+    pub fn ref_array(&self) -> [String; 9] {
+        let percent = |utilization: Option<f64>| {
+            utilization.map_or("-".to_string(), |u| format!("{:.1}%", u * 100.0))
+        };
+        [
+            self.name.clone(),
+            format!("{}", self.instances),
+            format!("{}", self.mbcs_carriers_count + self.sbcs_carriers_count),
+            human_byte_count(self.carriers_size()),
+            human_byte_count(self.blocks_size()),
+            percent(self.utilization()),
+            percent(utilization(self.mbcs_blocks_size, self.mbcs_carriers_size)),
+            percent(utilization(self.sbcs_blocks_size, self.sbcs_carriers_size)),
+            if self.is_fragmentation_suspect() {
+                "suspect".to_string()
+            } else {
+                "".to_string()
+            },
+        ]
+    }
+}
+
+// Reads the byte range of one section, so it can be streamed through a `BufReader` instead of
+// being loaded as a whole like `CrashDump::load_section` does.
+struct SectionReader<'a> {
+    file: &'a File,
+    offset: u64,
+    end: u64,
+}
+
+impl Read for SectionReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.end.saturating_sub(self.offset);
+        let max = (buf.len() as u64).min(remaining) as usize;
+        if max == 0 {
+            return Ok(0);
+        }
+        let read = self.file.read_at(&mut buf[..max], self.offset)?;
+        self.offset += read as u64;
+        Ok(read)
+    }
+}
+
+// A group of atoms sharing a prefix and differing only by a numeric suffix, e.g. `worker_1`,
+// `worker_2`, ... These usually come from `list_to_atom` on generated names, which is how atom
+// tables get exhausted.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct AtomFamily {
+    pub prefix: String,
+    pub count: usize,
+    pub min_suffix: u64,
+    pub max_suffix: u64,
+}
+
+// Smallest number of atoms sharing a prefix that is reported as a family.
+pub const ATOM_FAMILY_MIN_SIZE: usize = 50;
+
+impl AtomFamily {
+    // Groups the atoms by prefix and returns the families with at least
+    // `ATOM_FAMILY_MIN_SIZE` members, largest first.
+    pub fn detect<S: AsRef<str>>(atoms: impl IntoIterator<Item = S>) -> Vec<AtomFamily> {
+        let mut families: HashMap<String, AtomFamily> = HashMap::new();
+        for atom in atoms {
+            let atom = atom.as_ref();
+            // quoted atoms are printed as 'Some atom'
+            let atom = atom
+                .strip_prefix('\'')
+                .and_then(|a| a.strip_suffix('\''))
+                .unwrap_or(atom);
+            let prefix = atom.trim_end_matches(|c: char| c.is_ascii_digit());
+            if prefix.len() == atom.len() || prefix.is_empty() {
+                continue;
+            }
+            let Ok(suffix) = atom[prefix.len()..].parse::<u64>() else {
+                continue;
+            };
+            let family = families
+                .entry(prefix.to_string())
+                .or_insert_with(|| AtomFamily {
+                    prefix: prefix.to_string(),
+                    count: 0,
+                    min_suffix: suffix,
+                    max_suffix: suffix,
+                });
+            family.count += 1;
+            family.min_suffix = family.min_suffix.min(suffix);
+            family.max_suffix = family.max_suffix.max(suffix);
+        }
+
+        let mut families: Vec<AtomFamily> = families
+            .into_values()
+            .filter(|family| family.count >= ATOM_FAMILY_MIN_SIZE)
+            .collect();
+        families.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.prefix.cmp(&b.prefix)));
+        families
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct IndexTableInfo {
+    // Table name, e.g. `atom_tab` or `module_code`
+    pub name: String,
+    pub size: i64,
+    // Maximum number of entries, the VM crashes when it is reached
+    pub limit: i64,
+    pub entries: i64,
+}
+
+impl IndexTableInfo {
+    // entries / limit, `None` if the dump has no limit for the table
+    pub fn usage(&self) -> Option<f64> {
+        if self.limit > 0 {
+            Some(self.entries as f64 / self.limit as f64)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_near_limit(&self) -> bool {
+        self.usage()
+            .is_some_and(|usage| usage > INDEX_TABLE_WARNING_USAGE)
+    }
+
+    pub fn headers() -> [&'static str; 5] {
+        ["Table", "Size", "Entries", "Limit", "Usage"]
+    }
+
+    pub fn ref_array(&self) -> [String; 5] {
+        [
+            self.name.clone(),
+            self.size.to_string(),
+            self.entries.to_string(),
+            self.limit.to_string(),
+            self.usage()
+                .map_or("".to_string(), |usage| format!("{:.1}%", usage * 100.0)),
+        ]
+    }
+}
+
+impl FromGenericSection for IndexTableInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let get = |key: &str| {
+            section
+                .data
+                .get(key)
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0)
+        };
+        Ok(IndexTableInfo {
+            name: section.id.clone().unwrap_or_default(),
+            size: get("size"),
+            limit: get("limit"),
+            entries: get("entries"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::IndexTable(self)
+    }
+}
+
+// An index table is flagged once it holds this fraction of its limit
+pub const INDEX_TABLE_WARNING_USAGE: f64 = 0.9;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct HashTableInfo {
+    // Table name, e.g. `atom_tab` or `process_reg`
+    pub name: String,
+    // Number of buckets
+    pub size: i64,
+    // Number of buckets in use
+    pub used: i64,
+    pub objs: i64,
+    // Length of the longest bucket chain
+    pub depth: i64,
+}
+
+impl HashTableInfo {
+    pub fn headers() -> [&'static str; 5] {
+        ["Table", "Size", "Used", "Objects", "Depth"]
+    }
+
+    pub fn ref_array(&self) -> [String; 5] {
+        [
+            self.name.clone(),
+            self.size.to_string(),
+            self.used.to_string(),
+            self.objs.to_string(),
+            self.depth.to_string(),
+        ]
+    }
+}
+
+impl FromGenericSection for HashTableInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let get = |key: &str| {
+            section
+                .data
+                .get(key)
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0)
+        };
+        Ok(HashTableInfo {
+            name: section.id.clone().unwrap_or_default(),
+            size: get("size"),
+            used: get("used"),
+            objs: get("objs"),
+            depth: get("depth"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::HashTable(self)
+    }
+}
+
+// A line of the `=allocated_areas` section, e.g. `atom_space: 131104 124984`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct AllocatedArea {
+    pub name: String,
+    // Allocated bytes
+    pub allocated: i64,
+    // Used bytes, only given for some areas
+    pub used: Option<i64>,
+}
+
+impl AllocatedArea {
+    pub fn headers() -> [&'static str; 3] {
+        ["Area", "Allocated", "Used"]
+    }
+
+    pub fn ref_array(&self) -> [String; 3] {
+        [
+            self.name.clone(),
+            human_byte_count(self.allocated),
+            self.used.map_or("".to_string(), human_byte_count),
+        ]
+    }
+}
+
+impl FromGenericSection for Vec<AllocatedArea> {
+    // Returns the areas of the section, largest first
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let mut areas: Vec<AllocatedArea> = section
+            .data
+            .iter()
+            .map(|(name, value)| {
+                let mut sizes = value.split_whitespace().map(|s| s.parse::<i64>().ok());
+                AllocatedArea {
+                    name: name.clone(),
+                    allocated: sizes.next().flatten().unwrap_or(0),
+                    used: sizes.next().flatten(),
+                }
+            })
+            .collect();
+        areas.sort_by(|a, b| {
+            b.allocated
+                .cmp(&a.allocated)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        Ok(areas)
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::AllocatedAreas(self)
+    }
+}
+
+// Decodes a `=binary` section, `<size>:<base64>` after its header line, into the length of the
+// binary and its bytes. With `limit`, only the first `limit` bytes are decoded, and the section
+// may be cut short after them.
+fn parse_binary_section(contents: &str, limit: Option<usize>) -> Result<BinaryHead, SectionError> {
+    let body = contents.split_once('\n').map_or("", |(_, body)| body);
+    let (size, data) = body
+        .split_once(':')
+        .ok_or_else(|| SectionError::Malformed(format!("invalid binary: {}", body)))?;
+    let size = usize::from_str_radix(size, 16)
+        .map_err(|e| SectionError::Malformed(format!("invalid binary size {}: {}", size, e)))?;
+    let data = data.lines().next().unwrap_or("");
+    let data = match limit {
+        // whole groups of 4 characters, as a cut section can end in the middle of one
+        Some(limit) => {
+            let len = data.len().min(limit.div_ceil(3) * 4) / 4 * 4;
+            data.get(..len).unwrap_or("")
+        }
+        None => data,
+    };
+    let mut bytes = BASE64_STANDARD
+        .decode(data)
+        .map_err(|e| SectionError::Malformed(format!("invalid binary data: {}", e)))?;
+    match limit {
+        Some(limit) => bytes.truncate(limit),
+        None if bytes.len() != size => {
+            return Err(SectionError::Malformed(format!(
+                "binary is {} bytes, expected {}",
+                bytes.len(),
+                size
+            )))
+        }
+        None => {}
+    }
+    Ok((size, bytes))
+}
+
+// Converts hexadecimal digits to decimal ones, however many there are, as bignums can be larger
+// than any integer type. `None` if `hex` is empty or not hexadecimal.
+pub(crate) fn hex_to_decimal(hex: &str) -> Option<String> {
+    const LIMB: u64 = 1_000_000_000;
+    if hex.is_empty() {
+        return None;
+    }
+    // the value in base 10^9, least significant limb first
+    let mut limbs: Vec<u64> = vec![0];
+    for c in hex.chars() {
+        let mut carry = c.to_digit(16)? as u64;
+        for limb in limbs.iter_mut() {
+            let value = *limb * 16 + carry;
+            *limb = value % LIMB;
+            carry = value / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+    }
+    let mut limbs = limbs.iter().rev();
+    let mut decimal = limbs.next()?.to_string();
+    for limb in limbs {
+        decimal.push_str(&format!("{:09}", limb));
+    }
+    Some(decimal)
+}
+
+// Splits an atom in the heap encoding, `A<hex length>:<name>`, from what follows it
+fn split_atom(data: &str) -> Option<(&str, &str)> {
+    let (len, rest) = data.strip_prefix('A')?.split_once(':')?;
+    let len = usize::from_str_radix(len, 16).ok()?;
+    if !rest.is_char_boundary(len) {
+        return None;
+    }
+    Some(rest.split_at(len))
+}
+
+// An entry of the fun table, from a `=fun` section
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct FunInfo {
+    pub module: String,
+    // Checksum of the code the fun was compiled from, the `Uniq` in `#Fun<Module.Index.Uniq>`
+    pub uniq: i64,
+    pub index: i64,
+    pub address: String,
+    // Number of fun objects referring to this entry
+    pub refc: i64,
+}
+
+impl FunInfo {
+    pub fn headers() -> [&'static str; 5] {
+        ["Fun", "Module", "Index", "Address", "Refc"]
+    }
+
+    pub fn ref_array(&self) -> [String; 5] {
+        [
+            format!("#Fun<{}.{}.{}>", self.module, self.index, self.uniq),
+            self.module.clone(),
+            self.index.to_string(),
+            self.address.clone(),
+            self.refc.to_string(),
+        ]
+    }
+}
+
+impl FromGenericSection for FunInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let get = |key: &str| {
+            section
+                .data
+                .get(key)
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0)
+        };
+        Ok(FunInfo {
+            module: section.data.get("Module").cloned().unwrap_or_default(),
+            uniq: get("Uniq"),
+            index: get("Index"),
+            address: section.data.get("Address").cloned().unwrap_or_default(),
+            refc: get("Refc"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Fun(self)
+    }
+}
+
+// Whether the dump was written completely, from its `=end` and `=abort` sections
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct TruncationInfo {
+    // True if the dump has no `=end` section or was aborted
+    pub truncated: bool,
+    // Reason given by an `=abort` section, e.g. a timeout from `ERL_CRASH_DUMP_SECONDS`
+    pub abort_reason: Option<String>,
+    // Sections lacking some of their keys, including the one a truncated dump stops in
+    pub incomplete_sections: usize,
+}
+
+// Strips the quotes an atom is printed with, e.g. `'nonode@nohost'`
+fn unquote_atom(atom: &str) -> &str {
+    atom.strip_prefix('\'')
+        .and_then(|atom| atom.strip_suffix('\''))
+        .unwrap_or(atom)
+}
+
+// Distribution state of the crashed node, from the `=node` and `=no_distribution` sections
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct DistributionInfo {
+    // Name of the crashed node, `nonode@nohost` if it was not distributed
+    pub node_name: String,
+    pub distributed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub enum NodeType {
+    #[default]
+    Visible,
+    Hidden,
+    NotConnected,
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeType::Visible => write!(f, "visible"),
+            NodeType::Hidden => write!(f, "hidden"),
+            NodeType::NotConnected => write!(f, "not connected"),
+        }
+    }
+}
+
+impl NodeType {
+    fn from_tag(tag: &str) -> Self {
+        match tag {
+            TAG_HIDDEN_NODE => NodeType::Hidden,
+            TAG_NOT_CONNECTED => NodeType::NotConnected,
+            _ => NodeType::Visible,
+        }
+    }
+}
+
+// A node from a `=visible_node`, `=hidden_node` or `=not_connected` section
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct NodeInfo {
+    // Channel number, the first number of the pids and ports of this node
+    pub channel: String,
+    pub type_: NodeType,
     pub name: String,
-    pub type_: String,
-    pub status: String,
+    // Port or pid of the connection, absent for not connected nodes
+    pub controller: Option<String>,
+    pub creation: String,
+    // (local, remote) pairs
+    pub remote_links: Vec<(String, String)>,
+    pub remote_monitors: Vec<(String, String)>,
+    pub remotely_monitored_by: Vec<(String, String)>,
 }
 
-#[derive(Debug)]
+impl NodeInfo {
+    pub fn headers() -> [&'static str; 8] {
+        [
+            "Channel",
+            "Name",
+            "Type",
+            "Controller",
+            "Creation",
+            "Links",
+            "Monitoring",
+            "Monitored By",
+        ]
+    }
+
+    pub fn ref_array(&self) -> [String; 8] {
+        [
+            self.channel.clone(),
+            self.name.clone(),
+            self.type_.to_string(),
+            self.controller.clone().unwrap_or_default(),
+            self.creation.clone(),
+            self.remote_links.len().to_string(),
+            self.remote_monitors.len().to_string(),
+            self.remotely_monitored_by.len().to_string(),
+        ]
+    }
+}
+
+impl FromGenericSection for NodeInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let mut node = NodeInfo {
+            channel: section.id.clone().unwrap_or_default(),
+            type_: NodeType::from_tag(&section.tag),
+            ..Default::default()
+        };
+        for line in &section.raw_lines {
+            let Some((key, value)) = line.split_once(": ") else {
+                continue;
+            };
+            let value = value.trim();
+            let pair = || {
+                let (local, remote) = value.split_once(' ').unwrap_or((value, ""));
+                (local.to_string(), remote.trim().to_string())
+            };
+            match key {
+                "Name" => node.name = unquote_atom(value).to_string(),
+                "Controller" => node.controller = Some(value.to_string()),
+                "Creation" => node.creation = value.to_string(),
+                "Remote link" => node.remote_links.push(pair()),
+                "Remote monitoring" => node.remote_monitors.push(pair()),
+                "Remotely monitored by" => node.remotely_monitored_by.push(pair()),
+                _ => {}
+            }
+        }
+        Ok(node)
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Node(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+pub enum SchedulerType {
+    #[default]
+    Normal,
+    DirtyCpu,
+    DirtyIo,
+}
+
+impl fmt::Display for SchedulerType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchedulerType::Normal => write!(f, "normal"),
+            SchedulerType::DirtyCpu => write!(f, "dirty cpu"),
+            SchedulerType::DirtyIo => write!(f, "dirty io"),
+        }
+    }
+}
+
+impl SchedulerType {
+    fn from_tag(tag: &str) -> Self {
+        match tag {
+            TAG_DIRTY_CPU_SCHEDULER | TAG_DIRTY_CPU_RUN_QUEUE => SchedulerType::DirtyCpu,
+            TAG_DIRTY_IO_SCHEDULER | TAG_DIRTY_IO_RUN_QUEUE => SchedulerType::DirtyIo,
+            _ => SchedulerType::Normal,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct SchedulerInfo {
     pub id: i32,
+    pub type_: SchedulerType,
     pub sleep_info: SleepInfo,
     pub current_port: String,
+    // Only normal schedulers have their own run queue, dirty schedulers share a
+    // `=dirty_*_run_queue`.
     pub run_queue: RunQueue,
-    pub current_process: CurrentProcess,
+    // The process running on the scheduler when the dump was written, if any.
+    pub current_process: Option<CurrentProcess>,
 }
-#[derive(Debug)]
+
+impl SchedulerInfo {
+    pub fn headers() -> [&'static str; 10] {
+        [
+            "Id",
+            "Type",
+            "Sleep Flags",
+            "Max",
+            "High",
+            "Normal",
+            "Low",
+            "Port",
+            "Current Process",
+            "Current Port",
+        ]
+    }
+
+    pub fn ref_array(&self) -> [String; 10] {
+        [
+            format!("{}", self.id),
+            self.type_.to_string(),
+            self.sleep_info.flags.join(" | "),
+            format!("{}", self.run_queue.max_length),
+            format!("{}", self.run_queue.high_length),
+            format!("{}", self.run_queue.normal_length),
+            format!("{}", self.run_queue.low_length),
+            format!("{}", self.run_queue.port_length),
+            self.current_process
+                .as_ref()
+                .map(|p| p.pid.clone())
+                .unwrap_or_default(),
+            self.current_port.clone(),
+        ]
+    }
+}
+
+impl FromGenericSection for SchedulerInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+
+        let current_process = data
+            .get("Current Process")
+            .filter(|pid| !pid.is_empty())
+            .map(|pid| CurrentProcess {
+                pid: pid.clone(),
+                state: data
+                    .get("Current Process State")
+                    .cloned()
+                    .unwrap_or_default(),
+                internal_state: split_flags(data.get("Current Process Internal State"), '|'),
+                program_counter: data
+                    .get("Current Process Program counter")
+                    .and_then(|s| ProgramCounter::from_string(s))
+                    .unwrap_or_default(),
+                stack_trace: parse_stack_frames(&section.raw_lines),
+            });
+
+        Ok(SchedulerInfo {
+            id: section
+                .id
+                .as_ref()
+                .and_then(|id| id.parse::<i32>().ok())
+                .unwrap_or(0),
+            type_: SchedulerType::from_tag(&section.tag),
+            sleep_info: SleepInfo {
+                flags: split_flags(data.get("Scheduler Sleep Info Flags"), '|'),
+                aux_work: split_flags(data.get("Scheduler Sleep Info Aux Work"), '|'),
+            },
+            current_port: data.get("Current Port").cloned().unwrap_or_default(),
+            run_queue: RunQueue::from_data(data),
+            current_process,
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Scheduler(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct SleepInfo {
     pub flags: Vec<String>,
     pub aux_work: Vec<String>,
 }
-#[derive(Debug)]
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct RunQueue {
     pub max_length: i32,
     pub high_length: i32,
@@ -2174,7 +3664,46 @@ pub struct RunQueue {
     pub port_length: i32,
     pub flags: Vec<String>,
 }
-#[derive(Debug)]
+
+impl RunQueue {
+    fn from_data(data: &HashMap<String, String>) -> Self {
+        let get_length = |key: &str| {
+            data.get(key)
+                .and_then(|s| s.parse::<i32>().ok())
+                .unwrap_or(0)
+        };
+        RunQueue {
+            max_length: get_length("Run Queue Max Length"),
+            high_length: get_length("Run Queue High Length"),
+            normal_length: get_length("Run Queue Normal Length"),
+            low_length: get_length("Run Queue Low Length"),
+            port_length: get_length("Run Queue Port Length"),
+            flags: split_flags(data.get("Run Queue Flags"), '|'),
+        }
+    }
+}
+
+/// Run queue shared by the dirty CPU or dirty IO schedulers.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct DirtyRunQueueInfo {
+    pub type_: SchedulerType,
+    pub run_queue: RunQueue,
+}
+
+impl FromGenericSection for DirtyRunQueueInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        Ok(DirtyRunQueueInfo {
+            type_: SchedulerType::from_tag(&section.tag),
+            run_queue: RunQueue::from_data(&section.data),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::DirtyRunQueue(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct CurrentProcess {
     pub pid: String,
     pub state: String,
@@ -2182,24 +3711,131 @@ pub struct CurrentProcess {
     pub program_counter: ProgramCounter,
     pub stack_trace: Vec<StackFrame>,
 }
-#[derive(Debug)]
+
+// Splits a list of flags such as `SLEEPING | TSE_SLEEPING | WAITING`, dropping empty entries.
+fn split_flags(value: Option<&String>, separator: char) -> Vec<String> {
+    value
+        .map(|s| {
+            s.split(separator)
+                .map(|flag| flag.trim().to_string())
+                .filter(|flag| !flag.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct EtsInfo {
+    // The pid of the process owning the table.
     pub pid: String,
     pub slot: i64,
+    // The table identifier, which is the name for named tables.
     pub table: String,
     pub name: String,
     pub buckets: i32,
     pub chain_length: ChainLength,
     pub fixed: bool,
+    // The number of objects in the table.
     pub objects: i64,
+    // The memory used by the table (in words).
     pub words: i64,
+    // The table type (set, ordered_set, bag, duplicate_bag).
     pub type_: String,
+    // The table protection (public, protected, private).
     pub protection: String,
     pub compressed: bool,
     pub write_concurrency: bool,
     pub read_concurrency: bool,
 }
-#[derive(Debug)]
+
+impl EtsInfo {
+    pub fn headers() -> [&'static str; 8] {
+        [
+            "Owner",
+            "Name",
+            "Table",
+            "Type",
+            "Protection",
+            "Objects",
+            "Words",
+            "Memory",
+        ]
+    }
+
+    pub fn ref_array(&self, word_size: u8) -> [String; 8] {
+        [
+            self.pid.clone(),
+            self.name.clone(),
+            self.table.clone(),
+            self.type_.clone(),
+            self.protection.clone(),
+            format!("{}", self.objects),
+            format!("{}", self.words),
+            human_bytes(self.words, word_size),
+        ]
+    }
+}
+
+impl FromGenericSection for EtsInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+
+        let get_string = |key: &str| data.get(key).cloned().unwrap_or_default();
+        let get_bool = |key: &str| data.get(key).map(|s| s == "true").unwrap_or(false);
+        let get_f64 = |key: &str| {
+            data.get(key)
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.0)
+        };
+
+        Ok(EtsInfo {
+            pid: section.id.clone().unwrap_or_default(),
+            slot: data
+                .get("Slot")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            table: get_string("Table"),
+            name: get_string("Name"),
+            buckets: data
+                .get("Buckets")
+                .and_then(|s| s.parse::<i32>().ok())
+                .unwrap_or(0),
+            chain_length: ChainLength {
+                avg: get_f64("Chain Length Avg"),
+                max: data
+                    .get("Chain Length Max")
+                    .and_then(|s| s.parse::<i32>().ok())
+                    .unwrap_or(0),
+                min: data
+                    .get("Chain Length Min")
+                    .and_then(|s| s.parse::<i32>().ok())
+                    .unwrap_or(0),
+                std_dev: get_f64("Chain Length Std Dev"),
+                expected_std_dev: get_f64("Chain Length Expected Std Dev"),
+            },
+            fixed: get_bool("Fixed"),
+            objects: data
+                .get("Objects")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            words: data
+                .get("Words")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            type_: get_string("Type"),
+            protection: get_string("Protection"),
+            compressed: get_bool("Compressed"),
+            write_concurrency: get_bool("Write Concurrency"),
+            read_concurrency: get_bool("Read Concurrency"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Ets(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct ChainLength {
     pub avg: f64,
     pub max: i32,
@@ -2207,84 +3843,379 @@ pub struct ChainLength {
     pub std_dev: f64,
     pub expected_std_dev: f64,
 }
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct TimerInfo {
+    // The process the timer message will be sent to
     pub pid: String,
-    pub message: serde_json::Value,
+    // The message as written in the dump
+    pub message: String,
+    // The message decoded with `CrashDump::decode`
+    pub decoded_message: Term,
+    // Milliseconds until the timer fires
     pub time_left: i64,
 }
-#[derive(Debug)]
+
+impl TimerInfo {
+    // Time left as e.g. `59m 39.382s`
+    pub fn format_time_left(&self) -> String {
+        let millis = self.time_left.max(0);
+        let (hours, minutes) = (millis / 3_600_000, millis / 60_000 % 60);
+        let seconds = format!("{}.{:03}s", millis / 1000 % 60, millis % 1000);
+        match (hours, minutes) {
+            (0, 0) => seconds,
+            (0, _) => format!("{}m {}", minutes, seconds),
+            _ => format!("{}h {}m {}", hours, minutes, seconds),
+        }
+    }
+
+    pub fn headers() -> [&'static str; 3] {
+        ["Pid", "Time Left", "Message"]
+    }
+
+    pub fn ref_array(&self) -> [String; 3] {
+        [
+            self.pid.clone(),
+            self.format_time_left(),
+            self.decoded_message.to_string(),
+        ]
+    }
+}
+
+impl FromGenericSection for TimerInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let message = section.data.get("Message").cloned().unwrap_or_default();
+        Ok(TimerInfo {
+            pid: section.id.clone().unwrap_or_default(),
+            decoded_message: Term::Raw(message.clone()),
+            message,
+            time_left: section
+                .data
+                .get("Time left")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Timer(self)
+    }
+}
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct LoadedModules {
+    // Total size of current code, in bytes
     pub current_code: i64,
+    // Total size of old code still loaded, in bytes
     pub old_code: i64,
+    // One entry per `=mod` section
     pub modules: Vec<ModuleInfo>,
 }
-#[derive(Debug)]
+
+impl FromGenericSection for LoadedModules {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let get_size = |key: &str| {
+            section
+                .data
+                .get(key)
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0)
+        };
+        Ok(LoadedModules {
+            current_code: get_size("Current code"),
+            old_code: get_size("Old code"),
+            modules: vec![],
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::LoadedModules(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct ModuleInfo {
     pub name: String,
+    // Size of the current code, in bytes
     pub current_size: i64,
+    // Size of the old code, only present while the module has old code loaded
+    pub old_size: Option<i64>,
+    // Attributes and compilation info are external term format, base64 encoded
+    pub current_attributes: String,
+    pub current_compilation_info: String,
+    pub old_attributes: String,
+    pub old_compilation_info: String,
 }
-#[derive(Debug)]
+
+impl ModuleInfo {
+    pub fn has_old_code(&self) -> bool {
+        self.old_size.is_some()
+    }
+
+    pub fn headers() -> [&'static str; 4] {
+        ["Module", "Current Size", "Old Size", "Old Code"]
+    }
+
+    pub fn ref_array(&self) -> [String; 4] {
+        [
+            self.name.clone(),
+            human_byte_count(self.current_size),
+            self.old_size.map_or("".to_string(), human_byte_count),
+            if self.has_old_code() {
+                "yes".to_string()
+            } else {
+                "".to_string()
+            },
+        ]
+    }
+}
+
+impl FromGenericSection for ModuleInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+        let get_string = |key: &str| data.get(key).cloned().unwrap_or_default();
+        Ok(ModuleInfo {
+            name: section.id.clone().unwrap_or_default(),
+            current_size: data
+                .get("Current size")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            old_size: data.get("Old size").and_then(|s| s.parse::<i64>().ok()),
+            current_attributes: get_string("Current attributes"),
+            current_compilation_info: get_string("Current compilation info"),
+            old_attributes: get_string("Old attributes"),
+            old_compilation_info: get_string("Old compilation info"),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Mod(self)
+    }
+}
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct PersistentTermInfo {
     pub terms: Vec<PersistentTerm>,
+    // Lines without a `|`, reported as errors when the section is loaded
+    #[serde(skip)]
+    pub malformed_lines: Vec<String>,
+    // Where the section is, to report terms that fail to decode once the dump is loaded
+    #[serde(skip)]
+    pub index_row: Option<IndexRow>,
 }
-#[derive(Debug)]
+
+impl FromGenericSection for PersistentTermInfo {
+    // Each line of `=persistent_terms` is `<key>|<value>`, both in the heap encoding.
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let mut info = PersistentTermInfo::default();
+        for line in &section.raw_lines {
+            match line.split_once('|') {
+                Some((key, value)) => info.terms.push(PersistentTerm {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    ..Default::default()
+                }),
+                None => info.malformed_lines.push(line.clone()),
+            }
+        }
+        Ok(info)
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::PersistentTerms(self)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct PersistentTerm {
-    pub address: String,
-    pub value: Value,
+    // Key and value as written in the dump
+    pub key: String,
+    pub value: String,
+    // Key and value decoded with `CrashDump::decode`
+    pub decoded_key: Term,
+    pub decoded_value: Term,
+    // Estimated size of the key and value in words, see `CrashDump::estimate_term_size`
+    pub size: usize,
 }
-#[derive(Debug)]
+
+impl PersistentTerm {
+    pub fn headers() -> [&'static str; 3] {
+        ["Key", "Size", "Value"]
+    }
+
+    pub fn ref_array(&self, word_size: u8) -> [String; 3] {
+        [
+            self.decoded_key.to_string(),
+            human_bytes(self.size as i64, word_size),
+            self.decoded_value.to_string(),
+        ]
+    }
+}
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct PortInfo {
+    // The port identifier (e.g. `#Port<0.1>`).
     pub id: String,
+    // The port state flags (e.g. `CONNECTED`, `BINARY_IO`).
     pub state: Vec<String>,
     pub slot: i64,
+    // The pid of the connected (owning) process.
     pub connected: String,
+    // Pids and ports linked to this port.
     pub links: Vec<String>,
+    // Monitors held by this port.
+    pub monitors: Vec<String>,
     pub registered_as: String,
+    // The linked-in driver controlled by the port (e.g. `tcp_inet`), if any.
+    pub driver: String,
+    // The external or forker process controlled by the port, if any.
     pub external_process: String,
+    // What the port controls, as written in the dump (e.g. `Port controls linked-in driver: tcp_inet`).
+    pub controls: String,
+    // The number of bytes read from the port.
     pub input: i64,
+    // The number of bytes written to the port.
     pub output: i64,
+    // The number of bytes queued in the port.
     pub queue: i64,
 }
 
-#[derive(Debug, Clone, Copy)]
-pub enum ByteConversionError {
-    WordSizeNotSet,
+impl PortInfo {
+    pub fn headers() -> [&'static str; 8] {
+        [
+            "Id",
+            "Connected",
+            "Name",
+            "Controls",
+            "State",
+            "Input",
+            "Output",
+            "Queue",
+        ]
+    }
+
+    pub fn ref_array(&self) -> [String; 8] {
+        [
+            self.id.clone(),
+            self.connected.clone(),
+            self.registered_as.clone(),
+            self.controls.clone(),
+            self.state.join("|"),
+            format!("{}", self.input),
+            format!("{}", self.output),
+            format!("{}", self.queue),
+        ]
+    }
 }
 
-impl fmt::Display for ByteConversionError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ByteConversionError::WordSizeNotSet => {
-                write!(f, "WORD_SIZE has not been initialized yet")
-            }
-        }
+impl FromGenericSection for PortInfo {
+    fn from_generic_section(section: &GenericSection) -> Result<Self, SectionError> {
+        let data = &section.data;
+
+        let split_list = |key: &str| -> Vec<String> {
+            data.get(key)
+                .map(|s| {
+                    s.split(", ")
+                        .map(|item| item.trim().to_string())
+                        .filter(|item| !item.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let driver = data
+            .get("Port controls linked-in driver")
+            .cloned()
+            .unwrap_or_default();
+        let external_process = data
+            .get("Port controls external process")
+            .or_else(|| data.get("Port controls forker process"))
+            .cloned()
+            .unwrap_or_default();
+
+        // A section can have several of these lines, so the one shown is picked in a fixed
+        // order: the linked-in driver, then the external or forker process, then `Port is`.
+        let mut other_keys: Vec<&String> = data
+            .keys()
+            .filter(|key| key.starts_with("Port controls ") || key.starts_with("Port is "))
+            .collect();
+        other_keys.sort_by_key(|key| (key.starts_with("Port is "), key.as_str()));
+        let controls = [
+            "Port controls linked-in driver",
+            "Port controls external process",
+            "Port controls forker process",
+        ]
+        .into_iter()
+        .chain(other_keys.into_iter().map(String::as_str))
+        .find_map(|key| data.get(key).map(|value| format!("{}: {}", key, value)))
+        .unwrap_or_default();
+
+        Ok(PortInfo {
+            id: section.id.clone().unwrap_or_default(),
+            state: split_flags(data.get("State"), '|'),
+            slot: data
+                .get("Slot")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            connected: data.get("Connected").cloned().unwrap_or_default(),
+            links: split_list("Links"),
+            monitors: split_list("Monitors"),
+            registered_as: data.get("Registered as").cloned().unwrap_or_default(),
+            driver,
+            external_process,
+            controls,
+            input: data
+                .get("Input")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            output: data
+                .get("Output")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+            queue: data
+                .get("Queue")
+                .and_then(|s| s.parse::<i64>().ok())
+                .unwrap_or(0),
+        })
+    }
+
+    fn into_dump_section(self) -> DumpSection {
+        DumpSection::Port(self)
     }
 }
 
+/// Sort key ordering pids such as `<0.100.0>` and ports such as `#Port<0.10>` by their numbers
+/// rather than as text, where `<0.100.0>` would come before `<0.11.0>`.
+pub fn id_sort_key(id: &str) -> (Vec<u64>, String) {
+    let numbers = id
+        .rsplit_once('<')
+        .and_then(|(_, rest)| rest.strip_suffix('>'))
+        .map(|numbers| numbers.split('.').map_while(|n| n.parse().ok()).collect())
+        .unwrap_or_default();
+    (numbers, id.to_string())
+}
+
 // given an arbitrary word count, multiply it by the word size and give some reasonable string estimation.
 // human readable heuristics should be to provide GB, MB, KB, or B, depending on what's the most "readable"
 
-pub fn human_bytes(word_count: i64) -> String {
-    convert_to_human_readable_bytes(word_count, None).unwrap()
+pub fn human_bytes(word_count: i64, word_size: u8) -> String {
+    convert_to_human_readable_bytes(word_count, word_size, None)
 }
 
 pub fn convert_to_human_readable_bytes(
     word_count: i64,
+    word_size: u8,
     precision: Option<usize>,
-) -> Result<String, ByteConversionError> {
-    let word_size_option = WORD_SIZE.get();
+) -> String {
+    format_byte_count(word_count * word_size as i64, precision)
+}
 
-    let word_size = match word_size_option {
-        Some(size) => *size as i64,
-        None => return Err(ByteConversionError::WordSizeNotSet),
-    };
+// Same as `human_bytes`, for values that are already in bytes, such as allocator sizes.
+pub fn human_byte_count(bytes: i64) -> String {
+    format_byte_count(bytes, None)
+}
 
-    let actual_bytes = word_count * word_size;
+fn format_byte_count(actual_bytes: i64, precision: Option<usize>) -> String {
     let precision = precision.unwrap_or(2); // Default to 2 decimal places
 
     if actual_bytes == 0 {
-        return Ok("0 B".to_string());
+        return "0 B".to_string();
     }
 
     let units = ["B", "KB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
@@ -2307,5 +4238,195 @@ pub fn convert_to_human_readable_bytes(
         format!("{:.precision$}", size, precision = precision)
     };
 
-    Ok(format!("{} {}", formatted_size, units[unit_index]))
+    format!("{} {}", formatted_size, units[unit_index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preamble_without_calling_thread() {
+        let preamble = "=erl_crash_dump:0.5\nMon Jan  1 00:00:00 2024\nSlogan: init terminating\n\
+                        System version: Erlang/OTP 20 [erts-9.0] [64-bit]\nAtoms: 9000\n";
+        assert!(!is_section_incomplete(&Tag::Preamble, preamble));
+        let cut_short = "=erl_crash_dump:0.5\nMon Jan  1 00:00:00 2024\nSlogan: init\n";
+        assert!(is_section_incomplete(&Tag::Preamble, cut_short));
+    }
+
+    #[test]
+    fn ids_sort_by_number() {
+        let mut pids = vec!["<0.100.0>", "<0.11.0>", "<0.2.0>", "<1.0.0>"];
+        pids.sort_by_cached_key(|pid| id_sort_key(pid));
+        assert_eq!(pids, ["<0.2.0>", "<0.11.0>", "<0.100.0>", "<1.0.0>"]);
+        let mut ports = vec!["#Port<0.10>", "#Port<0.9>"];
+        ports.sort_by_cached_key(|port| id_sort_key(port));
+        assert_eq!(ports, ["#Port<0.9>", "#Port<0.10>"]);
+    }
+
+    #[test]
+    fn port_controls_priority() {
+        let section = GenericSection::from_str(
+            "=port:#Port<0.5>\n\
+             State: CONNECTED\n\
+             Port is UNIX fd not opened by emulator: 2/2\n\
+             Port controls forker process: forker\n\
+             Port controls linked-in driver: tcp_inet\n",
+        )
+        .unwrap();
+        let port = PortInfo::from_generic_section(&section).unwrap();
+        assert_eq!(port.driver, "tcp_inet");
+        assert_eq!(port.external_process, "forker");
+        assert_eq!(port.controls, "Port controls linked-in driver: tcp_inet");
+    }
+
+    #[test]
+    fn hex_to_decimal_digits() {
+        assert_eq!(hex_to_decimal("0").as_deref(), Some("0"));
+        assert_eq!(hex_to_decimal("00FF").as_deref(), Some("255"));
+        assert_eq!(
+            hex_to_decimal("FFFFFFFFFFFFFFFFFF").as_deref(),
+            Some("4722366482869645213695")
+        );
+        assert_eq!(
+            hex_to_decimal("10000000000000000").as_deref(),
+            Some("18446744073709551616")
+        );
+    }
+
+    #[test]
+    fn hex_to_decimal_malformed() {
+        assert_eq!(hex_to_decimal(""), None);
+        assert_eq!(hex_to_decimal("12G4"), None);
+        assert_eq!(hex_to_decimal("-1"), None);
+    }
+
+    #[test]
+    fn bignum() {
+        let crash_dump = CrashDump::new();
+        assert_eq!(
+            crash_dump.decode("B16#FFFFFFFFFFFFFFFFFF"),
+            Term::Bignum("4722366482869645213695".to_string())
+        );
+        assert_eq!(
+            crash_dump.decode("B-16#FFFFFFFFFFFFFFFFFF"),
+            Term::Bignum("-4722366482869645213695".to_string())
+        );
+        assert_eq!(
+            crash_dump.decode("B-123456789012345678901234"),
+            Term::Bignum("-123456789012345678901234".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_bignum() {
+        // left as they are in the dump
+        let crash_dump = CrashDump::new();
+        for data in ["B16#", "B16#XYZ", "B-16#", "B-12a"] {
+            assert_eq!(crash_dump.decode(data), Term::Raw(data.to_string()));
+        }
+    }
+
+    #[test]
+    fn local_fun() {
+        let mut crash_dump = CrashDump::new();
+        let fun = crash_dump.decode("FuA3:foo:1:2A:0");
+        assert_eq!(
+            fun,
+            Term::Fun(FunTerm::Local {
+                module: "foo".to_string(),
+                index: 1,
+                uniq: 42,
+                arity: None,
+                refc: None,
+            })
+        );
+        assert_eq!(fun.to_string(), "#Fun<foo.1.42>");
+
+        // the reference count comes from the fun table, and free variables are skipped
+        crash_dump.funs.push(FunInfo {
+            module: "foo".to_string(),
+            uniq: 42,
+            index: 1,
+            address: "0x00007f0a2c1b2e40".to_string(),
+            refc: 3,
+        });
+        assert_eq!(
+            crash_dump.decode("FuA3:foo:1:2A:1,I5").to_string(),
+            "#Fun<foo.1.42> (refc 3)"
+        );
+    }
+
+    #[test]
+    fn external_fun() {
+        let fun = CrashDump::new().decode("FeA5:lists:A3:map:2");
+        assert_eq!(
+            fun,
+            Term::Fun(FunTerm::External {
+                module: "lists".to_string(),
+                function: "map".to_string(),
+                arity: 2,
+            })
+        );
+        assert_eq!(fun.to_string(), "fun lists:map/2");
+    }
+
+    #[test]
+    fn undecodable_persistent_term() {
+        let mut crash_dump = CrashDump::new();
+        crash_dump
+            .persistent_terms
+            .push(InfoOrIndex::Info(PersistentTermInfo {
+                // an external term with an unknown tag
+                terms: vec![PersistentTerm {
+                    key: "A3:key".to_string(),
+                    value: "E2:gwA=".to_string(),
+                    ..Default::default()
+                }],
+                index_row: Some(IndexRow {
+                    r#type: TAG_PERSISTENT_TERMS.to_string(),
+                    id: None,
+                    start: "42".to_string(),
+                    length: "10".to_string(),
+                }),
+                ..Default::default()
+            }));
+        crash_dump.decode_deferred_terms();
+        let InfoOrIndex::Info(info) = &crash_dump.persistent_terms[0] else {
+            unreachable!()
+        };
+        assert_eq!(
+            info.terms[0].decoded_value,
+            Term::Raw("E2:gwA=".to_string())
+        );
+        assert_eq!(crash_dump.errors.len(), 1);
+        assert_eq!(crash_dump.errors[0].offset(), Some(42));
+    }
+
+    #[test]
+    fn module_users_with_unreadable_stack() {
+        let crash_dump = CrashDump::new();
+        // a stack section past the end of the dump
+        crash_dump.processes_stack.insert(
+            "<0.1.0>".to_string(),
+            InfoOrIndex::Index(IndexRow {
+                r#type: TAG_PROC_STACK.to_string(),
+                id: Some("<0.1.0>".to_string()),
+                start: u32::MAX.to_string(),
+                length: "10".to_string(),
+            }),
+        );
+        let file = File::open(file!()).unwrap();
+        let (users, errors) = crash_dump.module_users(&file);
+        assert!(users.is_empty());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].offset(), Some(u32::MAX as u64));
+    }
+
+    #[test]
+    fn unresolved_fun() {
+        let fun = CrashDump::new().decode("Rf144572560");
+        assert_eq!(fun, Term::Fun(FunTerm::Unresolved("144572560".to_string())));
+        assert_eq!(fun.to_string(), "#Fun<unresolved 0x144572560>");
+    }
 }
@@ -0,0 +1,538 @@
+// Copyright (c) Meta Platforms, Inc. and affiliates.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//     http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+//
+// Walker for terms in the heap encoding, e.g. `t2:A2:ok,H14B3C5AD0`.
+//
+// Terms on the process heap point to each other with `H<address>`, so decoding one means
+// following pointers through `CrashDump::all_heap_addresses`. The walker does this with an
+// explicit stack rather than recursion, so a long list, which is a chain of cons cells, or a
+// deeply nested term can't overflow the call stack.
+//
+// Each term gets a budget of nodes and bytes of the heap encoding. Once it is spent, what is
+// left of the term is kept as `Term::Truncated` with its heap encoding, which can be decoded
+// later with a budget of its own. A pointer back to a cell that is still being decoded, which
+// only a corrupted heap can have, is left truncated as well. Subterms shared by several parts
+// of a term, as is common in the literal area, are decoded each time they are reached.
+//
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::term::Term;
+use super::types::{CrashDump, SectionError};
+use std::collections::HashSet;
+
+/// How much of a term in the heap encoding is decoded before the rest is left as
+/// `Term::Truncated`. The first node of a term is always decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Tuples, list cells and map nodes decoded per term
+    pub max_nodes: usize,
+    /// Bytes of the heap encoding decoded per term
+    pub max_bytes: usize,
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        DecodeLimits {
+            max_nodes: 2048,
+            max_bytes: 64 * 1024,
+        }
+    }
+}
+
+// A step of the walk. Decoding a tuple, list or map pushes the steps that decode its parts,
+// then the one that builds it from their terms.
+enum Task {
+    Decode(String),
+    // The heap cell at this address is decoded
+    Leave(String),
+    Tuple(usize),
+    // The first elements of a list, with the tail of the cell holding the last one and the
+    // addresses of the cells after the first
+    Cons {
+        len: usize,
+        tail: String,
+        cells: Vec<String>,
+    },
+    // A list of this many elements, followed by its tail if it is improper or truncated
+    List(usize, bool),
+    // A flatmap, with its keys tuple followed by this many values
+    FlatMap {
+        data: String,
+        size: usize,
+    },
+    // The first key-value pairs of a hashmap, with the nodes left to walk and those walked
+    HashMap {
+        len: usize,
+        pending: Vec<String>,
+        visited: HashSet<String>,
+    },
+    // A map with this many key-value pairs, followed by the rest of it if it is truncated
+    Map(usize, bool),
+}
+
+/// Decodes `data` within `limits`. Errors are those of a malformed heap encoding.
+pub(super) fn walk(
+    crash_dump: &CrashDump,
+    data: &str,
+    limits: DecodeLimits,
+) -> Result<Term, SectionError> {
+    let mut walker = HeapWalker {
+        crash_dump,
+        limits,
+        nodes: 0,
+        bytes: 0,
+        path: HashSet::new(),
+        values: Vec::new(),
+    };
+    let mut tasks = vec![Task::Decode(data.to_string())];
+    while let Some(task) = tasks.pop() {
+        match task {
+            Task::Decode(data) => walker.decode(data, &mut tasks)?,
+            Task::Leave(addr) => {
+                walker.path.remove(&addr);
+            }
+            Task::Tuple(size) => {
+                let elements = walker.take(size);
+                walker.values.push(Term::Tuple(elements));
+            }
+            Task::Cons { len, tail, cells } => walker.cons(len, tail, cells, &mut tasks)?,
+            Task::List(len, improper) => {
+                let tail = if improper { walker.values.pop() } else { None };
+                let elements = walker.take(len);
+                walker.values.push(Term::List(elements, tail.map(Box::new)));
+            }
+            Task::FlatMap { data, size } => {
+                let values = walker.take(size);
+                let map = match walker.values.pop() {
+                    Some(Term::Tuple(keys)) if keys.len() == size => {
+                        Term::Map(keys.into_iter().zip(values).collect(), None)
+                    }
+                    // the keys are missing or truncated, and so is the map
+                    Some(keys @ Term::Unresolved(_)) => keys,
+                    Some(Term::Truncated(_)) => Term::Truncated(data),
+                    _ => return Err(format!("Invalid map format: {}", data).into()),
+                };
+                walker.values.push(map);
+            }
+            Task::HashMap {
+                len,
+                pending,
+                visited,
+            } => walker.hashmap(len, pending, visited, &mut tasks)?,
+            Task::Map(size, truncated) => {
+                let rest = if truncated { walker.values.pop() } else { None };
+                let mut terms = walker.take(2 * size).into_iter();
+                let mut pairs = Vec::with_capacity(size);
+                while let (Some(key), Some(value)) = (terms.next(), terms.next()) {
+                    pairs.push((key, value));
+                }
+                walker.values.push(Term::Map(pairs, rest.map(Box::new)));
+            }
+        }
+    }
+    walker
+        .values
+        .pop()
+        .ok_or_else(|| format!("Nothing decoded from {}", data).into())
+}
+
+struct HeapWalker<'a> {
+    crash_dump: &'a CrashDump,
+    limits: DecodeLimits,
+    nodes: usize,
+    bytes: usize,
+    // Addresses of the heap cells being decoded, to tell a cycle from a shared subterm
+    path: HashSet<String>,
+    // Terms decoded so far, waiting for the term they are part of to be built
+    values: Vec<Term>,
+}
+
+impl HeapWalker<'_> {
+    fn exhausted(&self) -> bool {
+        self.nodes >= self.limits.max_nodes || self.bytes >= self.limits.max_bytes
+    }
+
+    fn spend(&mut self, data: &str) {
+        self.nodes += 1;
+        self.bytes += data.len();
+    }
+
+    // The last `count` terms decoded, in order
+    fn take(&mut self, count: usize) -> Vec<Term> {
+        let start = self.values.len().saturating_sub(count);
+        self.values.split_off(start)
+    }
+
+    fn decode(&mut self, data: String, tasks: &mut Vec<Task>) -> Result<(), SectionError> {
+        // immediates and the like are part of the encoding of the term holding them, so only
+        // what is on the heap counts against the budget
+        let on_heap = matches!(data.chars().next(), Some('H' | 't' | 'l' | 'M'));
+        if on_heap && self.exhausted() {
+            self.values.push(Term::Truncated(data));
+            return Ok(());
+        }
+        if let Some(addr) = data.strip_prefix('H') {
+            if self.path.contains(addr) {
+                self.values.push(Term::Truncated(data));
+                return Ok(());
+            }
+            match self.crash_dump.all_heap_addresses.get(addr) {
+                Some(heap_data) => {
+                    self.path.insert(addr.to_string());
+                    tasks.push(Task::Leave(addr.to_string()));
+                    tasks.push(Task::Decode(heap_data.value().clone()));
+                }
+                None => self.values.push(Term::Unresolved(addr.to_string())),
+            }
+            return Ok(());
+        }
+
+        if !on_heap {
+            let term = self.crash_dump.parse_leaf(&data)?;
+            self.values.push(term);
+            return Ok(());
+        }
+        self.spend(&data);
+        match data.chars().next() {
+            Some('t') => self.tuple(&data, tasks),
+            Some('l') => self.list(data, 0, Vec::new(), tasks),
+            _ => self.map(&data, tasks),
+        }
+    }
+
+    // `t<arity>:<element>,<element>...`, with the arity in hex
+    fn tuple(&mut self, data: &str, tasks: &mut Vec<Task>) -> Result<(), SectionError> {
+        let (arity, elements) = data[1..]
+            .split_once(':')
+            .filter(|(arity, _)| arity.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| format!("Invalid tuple size format: {}", data))?;
+        let elements: Vec<&str> = if elements.is_empty() || arity == "0" {
+            vec![]
+        } else {
+            elements.split(',').collect()
+        };
+        tasks.push(Task::Tuple(elements.len()));
+        tasks.extend(
+            elements
+                .into_iter()
+                .rev()
+                .map(|element| Task::Decode(element.to_string())),
+        );
+        Ok(())
+    }
+
+    // `l<head>|<tail>`. The tail is usually a pointer to the next cell, which is followed once
+    // the head is decoded, so that the list is decoded into its elements rather than nested
+    // cells. The list is improper if it ends with anything else than `N`, and ends with a
+    // truncated tail if the budget runs out before it does.
+    fn list(
+        &mut self,
+        cell: String,
+        len: usize,
+        cells: Vec<String>,
+        tasks: &mut Vec<Task>,
+    ) -> Result<(), SectionError> {
+        let (head, tail) = cell[1..]
+            .split_once('|')
+            .ok_or_else(|| format!("Invalid list format: {}", cell))?;
+        tasks.push(Task::Cons {
+            len: len + 1,
+            tail: tail.to_string(),
+            cells,
+        });
+        tasks.push(Task::Decode(head.to_string()));
+        Ok(())
+    }
+
+    // Moves on to the cell after the `len` elements decoded so far, or builds the list
+    fn cons(
+        &mut self,
+        len: usize,
+        tail: String,
+        mut cells: Vec<String>,
+        tasks: &mut Vec<Task>,
+    ) -> Result<(), SectionError> {
+        if tail != "N" {
+            let next_cell = tail
+                .strip_prefix('H')
+                .filter(|addr| !self.exhausted() && !self.path.contains(*addr))
+                .and_then(|addr| self.crash_dump.all_heap_addresses.get(addr))
+                .filter(|heap_data| heap_data.value().starts_with('l'))
+                .map(|heap_data| heap_data.value().clone());
+            if let Some(next_cell) = next_cell {
+                let addr = tail[1..].to_string();
+                self.path.insert(addr.clone());
+                cells.push(addr);
+                self.spend(&next_cell);
+                return self.list(next_cell, len, cells, tasks);
+            }
+        }
+
+        // the cells of the list stay on the path until its tail is decoded
+        tasks.extend(cells.into_iter().map(Task::Leave));
+        if tail == "N" {
+            tasks.push(Task::List(len, false));
+        } else {
+            tasks.push(Task::List(len, true));
+            tasks.push(Task::Decode(tail));
+        }
+        Ok(())
+    }
+
+    // A flatmap, `Mf<size>:<keys>:<values>`, points to a tuple of its keys, followed by its
+    // values. Larger maps are HAMTs: the head node, `Mh<size>:<n>:<nodes>`, and the interior
+    // nodes, `Mn<n>:<nodes>`, point to `n` other interior nodes or leaves, which are
+    // `[Key | Value]` list cells.
+    fn map(&mut self, data: &str, tasks: &mut Vec<Task>) -> Result<(), SectionError> {
+        let invalid = || SectionError::from(format!("Invalid map format: {}", data));
+        let nodes = match data.get(1..2).unwrap_or("") {
+            "f" => {
+                let (size, rest) = data[2..].split_once(':').ok_or_else(invalid)?;
+                let size = usize::from_str_radix(size, 16).map_err(|_| invalid())?;
+                let (keys, values) = rest.split_once(':').ok_or_else(invalid)?;
+                let values: Vec<&str> = if size == 0 {
+                    vec![]
+                } else {
+                    values.split(',').collect()
+                };
+                if values.len() != size {
+                    return Err(invalid());
+                }
+                tasks.push(Task::FlatMap {
+                    data: data.to_string(),
+                    size,
+                });
+                tasks.extend(
+                    values
+                        .into_iter()
+                        .rev()
+                        .map(|value| Task::Decode(value.to_string())),
+                );
+                tasks.push(Task::Decode(keys.to_string()));
+                return Ok(());
+            }
+            "h" => {
+                let (_size, rest) = data[2..].split_once(':').ok_or_else(invalid)?;
+                rest.split_once(':').ok_or_else(invalid)?.1
+            }
+            // an interior node on its own, as when it is shown on the heap
+            "n" => data[2..].split_once(':').ok_or_else(invalid)?.1,
+            _ => return Err(invalid()),
+        };
+
+        let pending = nodes.rsplit(',').map(|node| node.to_string()).collect();
+        self.hashmap(0, pending, HashSet::new(), tasks)
+    }
+
+    // Walks the nodes of a hashmap until it reaches a leaf, whose key and value are decoded
+    // before the walk goes on. The rest of the map is left truncated if the budget runs out
+    // before the walk is done.
+    fn hashmap(
+        &mut self,
+        mut len: usize,
+        mut pending: Vec<String>,
+        mut visited: HashSet<String>,
+        tasks: &mut Vec<Task>,
+    ) -> Result<(), SectionError> {
+        while let Some(node) = pending.pop() {
+            let Some(addr) = node.strip_prefix('H') else {
+                return Err(format!("Invalid hashmap node: {}", node).into());
+            };
+            // a corrupted heap could link nodes in a loop
+            if !visited.insert(addr.to_string()) {
+                continue;
+            }
+            if self.exhausted() {
+                pending.push(node);
+                // the nodes left, as an interior node whose bitmap the walker doesn't need
+                let nodes: Vec<String> = pending.into_iter().rev().collect();
+                let rest = format!("Mn0:{}", nodes.join(","));
+                self.values.push(Term::Truncated(rest));
+                tasks.push(Task::Map(len, true));
+                return Ok(());
+            }
+            let Some(node_data) = self
+                .crash_dump
+                .all_heap_addresses
+                .get(addr)
+                .map(|d| d.value().clone())
+            else {
+                // a node missing from the dump is kept as a pair that can't be resolved
+                len += 1;
+                self.values.push(Term::Unresolved(addr.to_string()));
+                self.values.push(Term::Unresolved(addr.to_string()));
+                continue;
+            };
+            self.spend(&node_data);
+            if let Some(leaf) = node_data.strip_prefix('l') {
+                let (key, value) = leaf
+                    .split_once('|')
+                    .ok_or_else(|| format!("Invalid hashmap leaf: {}", node_data))?;
+                tasks.push(Task::HashMap {
+                    len: len + 1,
+                    pending,
+                    visited,
+                });
+                tasks.push(Task::Decode(value.to_string()));
+                tasks.push(Task::Decode(key.to_string()));
+                return Ok(());
+            } else if let Some(interior) = node_data.strip_prefix("Mn") {
+                let (_, children) = interior
+                    .split_once(':')
+                    .ok_or_else(|| format!("Invalid hashmap node: {}", node_data))?;
+                pending.extend(children.rsplit(',').map(|child| child.to_string()));
+            } else {
+                return Err(format!("Invalid hashmap node: {}", node_data).into());
+            }
+        }
+        tasks.push(Task::Map(len, false));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crash_dump(heap: impl IntoIterator<Item = (String, String)>) -> CrashDump {
+        let crash_dump = CrashDump::new();
+        for (addr, data) in heap {
+            crash_dump.all_heap_addresses.insert(addr, data);
+        }
+        crash_dump
+    }
+
+    fn walk_default(crash_dump: &CrashDump, data: &str) -> Term {
+        walk(crash_dump, data, DecodeLimits::default()).unwrap()
+    }
+
+    #[test]
+    fn cycle() {
+        let heap = crash_dump([("A0".to_string(), "lI1|HA0".to_string())]);
+        let term = walk_default(&heap, "HA0");
+        assert_eq!(term.to_string(), "[1 | ...]");
+        let heap = crash_dump([("A0".to_string(), "t2:I1,HA0".to_string())]);
+        assert_eq!(walk_default(&heap, "HA0").to_string(), "{1, ...}");
+    }
+
+    #[test]
+    fn shared_subterm() {
+        let heap = crash_dump([
+            ("A0".to_string(), "t2:HB0,HB0".to_string()),
+            ("B0".to_string(), "t1:A2:ok".to_string()),
+        ]);
+        assert_eq!(walk_default(&heap, "HA0").to_string(), "{{ok}, {ok}}");
+    }
+
+    #[test]
+    fn long_list() {
+        let len = 5000;
+        let heap = crash_dump((0..len).map(|i| {
+            let tail = if i + 1 == len {
+                "N".to_string()
+            } else {
+                format!("H{:X}", i + 1)
+            };
+            (format!("{:X}", i), format!("lI{}|{}", i, tail))
+        }));
+        let Term::List(elements, Some(tail)) = walk_default(&heap, "H0") else {
+            panic!("expected a truncated list");
+        };
+        assert_eq!(elements.len(), 2048);
+        assert_eq!(elements[2047], Term::Integer(2047));
+        assert_eq!(*tail, Term::Truncated("H800".to_string()));
+
+        let limits = DecodeLimits {
+            max_nodes: usize::MAX,
+            max_bytes: usize::MAX,
+        };
+        let Term::List(elements, None) = walk(&heap, "H0", limits).unwrap() else {
+            panic!("expected a proper list");
+        };
+        assert_eq!(elements.len(), len);
+    }
+
+    #[test]
+    fn deep_nesting() {
+        let depth = 100_000;
+        let heap = crash_dump((0..depth).map(|i| (format!("{:X}", i), format!("t1:H{:X}", i + 1))));
+        let mut term = walk_default(&heap, "H0");
+        let mut nesting = 0;
+        while let Term::Tuple(mut elements) = term {
+            nesting += 1;
+            term = elements.pop().unwrap();
+        }
+        assert_eq!(nesting, 2048);
+        assert_eq!(term, Term::Truncated("H800".to_string()));
+    }
+
+    #[test]
+    fn hashmap_budget() {
+        let leaves = 100;
+        let nodes: Vec<String> = (0..leaves).map(|i| format!("H{:X}", i)).collect();
+        let heap = crash_dump((0..leaves).map(|i| (format!("{:X}", i), format!("lI{}|A2:ok", i))));
+        let map = format!("Mh{:X}:FFFF:{}", leaves, nodes.join(","));
+        let limits = DecodeLimits {
+            max_nodes: 10,
+            max_bytes: usize::MAX,
+        };
+        let Term::Map(pairs, Some(rest)) = walk(&heap, &map, limits).unwrap() else {
+            panic!("expected a truncated map");
+        };
+        // the map node itself counts against the budget
+        assert_eq!(pairs.len(), 9);
+        assert_eq!(
+            *rest,
+            Term::Truncated(format!("Mn0:{}", nodes[9..].join(",")))
+        );
+
+        // the rest decodes to the pairs left
+        let Term::Map(rest_pairs, None) = walk_default(&heap, rest_data(&rest)) else {
+            panic!("expected the rest of the map");
+        };
+        assert_eq!(rest_pairs.len(), leaves - 9);
+        assert_eq!(
+            rest_pairs[0],
+            (Term::Integer(9), Term::Atom("ok".to_string()))
+        );
+    }
+
+    #[test]
+    fn hashmap_missing_node() {
+        let heap = crash_dump([("A0".to_string(), "lI1|I2".to_string())]);
+        let term = walk_default(&heap, "Mh2:3:HA0,HB0");
+        assert_eq!(
+            term,
+            Term::Map(
+                vec![
+                    (Term::Integer(1), Term::Integer(2)),
+                    (
+                        Term::Unresolved("B0".to_string()),
+                        Term::Unresolved("B0".to_string())
+                    ),
+                ],
+                None
+            )
+        );
+    }
+
+    fn rest_data(term: &Term) -> &str {
+        match term {
+            Term::Truncated(data) => data,
+            _ => panic!("expected a truncated term"),
+        }
+    }
+}